@@ -0,0 +1,61 @@
+//! Helper functions for the [`adjust` closure](crate::define#adjust-closure)
+//! that modify the value in place, without reallocating it.
+//!
+//! The functions can be passed to the `adjust` argument directly:
+//! ```
+//! use prae::Wrapper;
+//!
+//! prae::define! {
+//!     #[derive(Debug)]
+//!     pub Text: String;
+//!     adjust prae::adjust::trim;
+//! }
+//!
+//! assert_eq!(Text::new("  hello  ").unwrap().get(), "hello");
+//! ```
+//! or called from the closure when several adjustments are needed:
+//! ```
+//! use prae::Wrapper;
+//!
+//! prae::define! {
+//!     #[derive(Debug)]
+//!     pub Tag: String;
+//!     adjust |t| {
+//!         prae::adjust::trim(t);
+//!         t.make_ascii_lowercase();
+//!     };
+//! }
+//!
+//! assert_eq!(Tag::new("  Rust ").unwrap().get(), "rust");
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Remove the leading and trailing whitespace of the string in place.
+///
+/// Unlike `*s = s.trim().to_owned()`, it never allocates.
+pub fn trim(s: &mut String) {
+    trim_end(s);
+    trim_start(s);
+}
+
+/// Remove the leading whitespace of the string in place.
+///
+/// The remaining characters are moved to the beginning of the buffer.
+pub fn trim_start(s: &mut String) {
+    let start = s.len() - s.trim_start().len();
+    s.drain(..start);
+}
+
+/// Remove the trailing whitespace of the string in place.
+pub fn trim_end(s: &mut String) {
+    let end = s.trim_end().len();
+    s.truncate(end);
+}
+
+/// Sort the vector and remove the duplicate elements in place.
+pub fn sort_dedup<T: Ord>(v: &mut Vec<T>) {
+    v.sort_unstable();
+    v.dedup();
+}
@@ -1,10 +1,47 @@
-use std::error::Error;
-use std::fmt;
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut, Range};
+
+use crate::__private::Token;
 
 /// A trait that describes a
 /// [`Newtype`](https://rust-unofficial.github.io/patterns/patterns/behavioural/newtype.html)
 /// wrapper struct generated by [`define!`](crate::define) and
 /// [`extend!`](crate::extend) macros.
+///
+/// The naming of the methods follows a simple convention: methods that can
+/// fail the validation ([`Self::new`](Self::new),
+/// [`Self::try_set`](Self::try_set), [`Self::mutate`](Self::mutate) and
+/// others) return a [`Result`] with a wrapper-error. The only exception is
+/// [`Self::set`](Self::set), which panics: just like in the standard library,
+/// its fallible counterpart has the `try_` prefix. The validation can only be
+/// skipped by [`Self::new_unprocessed`](Self::new_unprocessed) and
+/// `as_inner_mut_unchecked` (under the `unprocessed` feature). If an invalid
+/// value is a bug in your code, use [`Result::expect`] or panic with the
+/// [`Display`](fmt::Display) of the error, which contains the name of the
+/// wrapper and the offending value:
+/// ```should_panic
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Username: String;
+///     ensure |u| !u.is_empty();
+/// }
+///
+/// let mut u = Username::new("user").unwrap();
+/// if let Err(err) = u.mutate(|u| u.clear()) {
+///     // Panics with `failed to mutate type Username from value "user" to
+///     // value "": value is invalid`.
+///     panic!("{}", err);
+/// }
+/// ```
 pub trait Wrapper: Sized {
     /// Name of the wrapper. Will be used for nice error messages.
     const NAME: &'static str;
@@ -23,9 +60,144 @@ pub trait Wrapper: Sized {
     /// invocation of [`define!`](crate::define)/[`extend!`](crate::extend)
     /// macros (e.g. `adjust`, `ensure` and `validate`).
     ///
+    /// By default, it calls [`Self::ADJUST`](Self::ADJUST) and then
+    /// [`Self::VALIDATE`](Self::VALIDATE). Wrappers created with
+    /// [`extend!`](crate::extend) override it to run the `PROCESS` of the
+    /// extended wrapper before their own closures, so their own `adjust`
+    /// closure can rely on the value being valid for the extended wrapper.
+    ///
     /// If no closures were provided, this function will not mutate the value
     /// and always return `Ok(())`.
-    const PROCESS: fn(&mut Self::Inner) -> Result<(), Self::Error>;
+    const PROCESS: fn(&mut Self::Inner) -> Result<(), Self::Error> = |value| {
+        Self::ADJUST(value);
+        Self::VALIDATE(value)
+    };
+
+    /// The same function as [`Self::PROCESS`](Self::PROCESS), but its error
+    /// also contains the [`Rejection`] that tells where the value was
+    /// rejected. It's used by the constructors, so that the
+    /// [`ConstructionError`] can report it.
+    ///
+    /// By default, it calls [`Self::PROCESS`](Self::PROCESS) and attributes
+    /// every error to [`ProcessPhase::Validate`]. Wrappers whose `PROCESS` can
+    /// also fail in other phases (e.g. the ones with a fallible `adjust`
+    /// closure or created with [`extend!`](crate::extend)) override this
+    /// function instead, and their `PROCESS` just drops the [`Rejection`].
+    #[allow(clippy::type_complexity)]
+    const PROCESS_TRACED: fn(&mut Self::Inner) -> Result<(), (Self::Error, Rejection)> = |value| {
+        Self::PROCESS(value).map_err(|original| {
+            let rejection = Rejection {
+                phase: ProcessPhase::Validate,
+                wrapper: Self::NAME,
+            };
+            (original, rejection)
+        })
+    };
+
+    /// The function that will adjust wrapper's inner value without validating
+    /// it.
+    ///
+    /// It's behaviour is based on the `adjust` closures that were provided
+    /// during the invocation of [`define!`](crate::define)/
+    /// [`extend!`](crate::extend) macros. For wrappers created with
+    /// [`extend!`](crate::extend), it runs the `ADJUST` of the extended
+    /// wrapper first.
+    ///
+    /// Note that, unlike [`Self::PROCESS`](Self::PROCESS), it doesn't
+    /// validate the value between the adjustments of the extended and the
+    /// extending wrappers.
+    ///
+    /// If no `adjust` closures were provided, this function will not mutate
+    /// the value.
+    const ADJUST: fn(&mut Self::Inner);
+
+    /// The function that will validate wrapper's inner value without mutating
+    /// it.
+    ///
+    /// It's behaviour is based on the validation closures that were provided
+    /// during the invocation of [`define!`](crate::define)/
+    /// [`extend!`](crate::extend) macros (e.g. `ensure` and `validate`).
+    /// Unlike [`Self::PROCESS`](Self::PROCESS), it doesn't run the `adjust`
+    /// closures.
+    ///
+    /// If no validation closures were provided, this function will always
+    /// return `Ok(())`.
+    const VALIDATE: fn(&Self::Inner) -> Result<(), Self::Error>;
+
+    /// The function that will validate the transition from the old inner value
+    /// to the new one on every mutation of the wrapper. It's executed after
+    /// [`Self::PROCESS`](Self::PROCESS) and receives the old value as it's
+    /// first argument.
+    ///
+    /// It's behaviour is based on the `validate_transition` closure that was
+    /// provided during the invocation of [`define!`](crate::define) macro. By
+    /// default, it always returns `Ok(())`.
+    #[allow(clippy::type_complexity)]
+    const VALIDATE_TRANSITION: fn(&Self::Inner, &Self::Inner) -> Result<(), Self::Error> =
+        |_, _| Ok(());
+
+    /// Whether the inner value should be hidden in the [`fmt::Display`]
+    /// output of the errors of this wrapper.
+    ///
+    /// It's `true` if the `redact_value` flag was provided during the
+    /// invocation of [`define!`](crate::define) macro. Wrappers created with
+    /// [`extend!`](crate::extend) inherit it from the extended wrapper. By
+    /// default, it's `false`, and the value is printed using its
+    /// [`fmt::Debug`] implementation.
+    const REDACT_VALUE: bool = false;
+
+    /// The function that prints the inner value in the [`fmt::Display`]
+    /// output of the errors of this wrapper, unless
+    /// [`Self::REDACT_VALUE`](Self::REDACT_VALUE) is set.
+    ///
+    /// It's set by the `truncate_value` flag during the invocation of
+    /// [`define!`](crate::define) macro. Wrappers created with
+    /// [`extend!`](crate::extend) inherit it from the extended wrapper, unless
+    /// the inner type is widened. By default, it's `None`, and the value is
+    /// printed using its [`fmt::Debug`] implementation.
+    #[allow(clippy::type_complexity)]
+    const FMT_VALUE: Option<fn(&Self::Inner, &mut fmt::Formatter<'_>) -> fmt::Result> = None;
+
+    /// Check if the provided `value` passes [`Self::VALIDATE`](Self::VALIDATE).
+    ///
+    /// Note that the value is not adjusted, so it should already be in it's
+    /// adjusted form.
+    fn is_valid(value: &Self::Inner) -> bool {
+        Self::VALIDATE(value).is_ok()
+    }
+
+    /// Check the provided `value` with [`Self::VALIDATE`](Self::VALIDATE),
+    /// returning the error if it fails.
+    ///
+    /// Note that the value is not adjusted, so it should already be in it's
+    /// adjusted form.
+    fn check(value: &Self::Inner) -> Result<(), Self::Error> {
+        Self::VALIDATE(value)
+    }
+
+    /// Run [`Self::PROCESS`](Self::PROCESS) on the provided `value` without
+    /// constructing the wrapper.
+    ///
+    /// Unlike [`Self::check`], it adjusts the value first, exactly as the
+    /// constructors do. It's useful to reuse the logic of the wrapper on
+    /// values that are kept unwrapped, e.g. to skip invalid records during an
+    /// import:
+    /// ```
+    /// use prae::Wrapper;
+    ///
+    /// prae::define! {
+    ///     pub Username: String;
+    ///     adjust |u| *u = u.trim().to_owned();
+    ///     ensure |u: &String| !u.is_empty();
+    /// }
+    ///
+    /// let mut records = vec![" alice ".to_owned(), "  ".to_owned()];
+    /// records.retain_mut(|r| Username::process(r).is_ok());
+    /// assert_eq!(records, ["alice"]);
+    /// ```
+    fn process(value: &mut Self::Inner) -> Result<(), Self::Error> {
+        Self::PROCESS(value)
+    }
 
     /// Construct a new wrapper.
     ///
@@ -33,15 +205,280 @@ pub trait Wrapper: Sized {
     /// [`Self::PROCESS`](Self::PROCESS).
     fn new(value: impl Into<Self::Inner>) -> Result<Self, ConstructionError<Self>>;
 
+    /// Construct a new wrapper, discarding the error.
+    ///
+    /// It will return `None` if the provided `value` doesn't pass
+    /// [`Self::PROCESS`](Self::PROCESS).
+    fn new_opt(value: impl Into<Self::Inner>) -> Option<Self> {
+        Self::new(value).ok()
+    }
+
+    /// Construct a new wrapper from the value that can be fallibly converted
+    /// into the inner type.
+    ///
+    /// It's useful for the narrowing conversions, e.g. when the inner type is
+    /// `u8`, but the value is `u32`. The returned [`TryNewError`] tells the
+    /// conversion failure apart from the validation failure:
+    /// ```
+    /// use prae::{TryNewError, Wrapper};
+    ///
+    /// prae::define! {
+    ///     #[derive(Debug)]
+    ///     pub Percent: u8;
+    ///     ensure |p| *p <= 100;
+    /// }
+    ///
+    /// assert_eq!(*Percent::try_new(42u32).unwrap().get(), 42);
+    /// assert!(matches!(Percent::try_new(256u32), Err(TryNewError::Conversion(_))));
+    /// assert!(matches!(Percent::try_new(101u32), Err(TryNewError::Construction(_))));
+    /// ```
+    fn try_new<V>(value: V) -> Result<Self, TryNewError<Self, V>>
+    where
+        Self::Inner: TryFrom<V>,
+    {
+        let value = Self::Inner::try_from(value).map_err(TryNewError::Conversion)?;
+        Self::new(value).map_err(TryNewError::Construction)
+    }
+
+    /// Construct a new wrapper, retaining the input in case of an error.
+    ///
+    /// The value of [`ConstructionError`] is the adjusted value that failed the
+    /// validation. This method also keeps the value exactly as it was provided
+    /// (e.g. to show the user what they typed), which is why it's only
+    /// available when the inner type implements [`Clone`](Clone).
+    fn new_retaining_input(value: impl Into<Self::Inner>) -> Result<Self, RetainedInputError<Self>>
+    where
+        Self::Inner: Clone,
+    {
+        let value = value.into();
+        let input = value.clone();
+        Self::new(value).map_err(|error| RetainedInputError { input, error })
+    }
+
+    /// Construct a new wrapper, attaching the provided `span` to the error.
+    ///
+    /// It's useful for the wrappers constructed by parsers, so that the errors
+    /// can point back to the source of the value:
+    /// ```
+    /// use prae::Wrapper;
+    ///
+    /// prae::define! {
+    ///     #[derive(Debug)]
+    ///     pub Ident: String;
+    ///     ensure |i: &String| i.chars().all(char::is_alphanumeric);
+    /// }
+    ///
+    /// let err = Ident::new_spanned("a-b", 4..7).unwrap_err();
+    /// assert_eq!(err.span, 4..7);
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "failed to construct type Ident from value \"a-b\": value is invalid (at 4..7)",
+    /// );
+    /// ```
+    /// The span can be of any type, e.g. a line and column pair.
+    fn new_spanned<S>(
+        value: impl Into<Self::Inner>,
+        span: S,
+    ) -> Result<Self, Spanned<ConstructionError<Self>, S>> {
+        Self::new(value).map_err(|error| Spanned { error, span })
+    }
+
+    /// Construct a new wrapper by collecting the provided iterator into the
+    /// inner value.
+    ///
+    /// This method is only available when the inner type implements
+    /// [`FromIterator`](FromIterator). It will return an error if the
+    /// collected value doesn't pass [`Self::PROCESS`](Self::PROCESS).
+    fn from_iter_validated<I>(iter: I) -> Result<Self, ConstructionError<Self>>
+    where
+        I: IntoIterator,
+        Self::Inner: FromIterator<I::Item>,
+    {
+        Self::new(iter.into_iter().collect::<Self::Inner>())
+    }
+
+    /// Construct a new wrapper from every value of the provided iterator.
+    ///
+    /// Unlike collecting the results of [`Self::new`] into a [`Result`], this
+    /// method doesn't stop at the first failure. It returns either all the
+    /// wrappers or all the errors:
+    /// ```
+    /// use prae::Wrapper;
+    ///
+    /// prae::define! {
+    ///     #[derive(Debug)]
+    ///     pub Username: String;
+    ///     ensure |u: &String| !u.is_empty();
+    /// }
+    ///
+    /// let users = Username::new_all(["alice", "bob"]).unwrap();
+    /// assert_eq!(users.len(), 2);
+    ///
+    /// let errs = Username::new_all(["alice", "", "bob", ""]).unwrap_err();
+    /// assert_eq!(errs.len(), 2);
+    /// ```
+    fn new_all<I>(values: I) -> Result<Vec<Self>, Vec<ConstructionError<Self>>>
+    where
+        I: IntoIterator,
+        I::Item: Into<Self::Inner>,
+    {
+        let mut wrappers = Vec::new();
+        let mut errors = Vec::new();
+        for value in values {
+            match Self::new(value) {
+                Ok(wrapper) => wrappers.push(wrapper),
+                Err(err) => errors.push(err),
+            }
+        }
+        if errors.is_empty() {
+            Ok(wrappers)
+        } else {
+            Err(errors)
+        }
+    }
+
     /// Get a shared reference to the inner value.
     fn get(&self) -> &Self::Inner;
 
+    /// Get an owned clone of the inner value, keeping the wrapper.
+    ///
+    /// It's the same as `self.get().clone()`, and the borrowed counterpart of
+    /// [`Self::into_inner`](Self::into_inner):
+    /// ```
+    /// use prae::Wrapper;
+    ///
+    /// prae::define! {
+    ///     #[derive(Debug)]
+    ///     pub Username: String;
+    /// }
+    ///
+    /// let username = Username::new("user").unwrap();
+    /// let s: String = username.to_inner();
+    /// assert_eq!(s, *username.get());
+    /// ```
+    fn to_inner(&self) -> Self::Inner
+    where
+        Self::Inner: Clone,
+    {
+        self.get().clone()
+    }
+
+    /// Get the inner value as a [`Cow::Borrowed`](Cow::Borrowed).
+    ///
+    /// It's a building block for the APIs that accept a [`Cow`], so they can
+    /// borrow the inner value instead of cloning it, and only clone it when
+    /// the owned value is actually required.
+    fn as_cow(&self) -> Cow<'_, Self::Inner>
+    where
+        Self::Inner: Clone,
+    {
+        Cow::Borrowed(self.get())
+    }
+
+    /// Compute a value from a shared reference to the inner value.
+    ///
+    /// It's the same as calling `f(self.get())`, but reads better in the
+    /// iterator adapters and other closures. The result can borrow from the
+    /// inner value:
+    /// ```
+    /// use prae::Wrapper;
+    ///
+    /// prae::define! {
+    ///     #[derive(Debug)]
+    ///     pub Username: String;
+    /// }
+    ///
+    /// let users = [Username::new("alice").unwrap(), Username::new("bob").unwrap()];
+    /// let lens: Vec<_> = users.iter().map(|u| u.with(String::len)).collect();
+    /// assert_eq!(lens, [5, 3]);
+    /// ```
+    fn with<'a, R>(&'a self, f: impl FnOnce(&'a Self::Inner) -> R) -> R {
+        f(self.get())
+    }
+
     // TODO: maybe change `ConstructionError` to `ReplacementError`?
     /// Replace inner value with the provided one.
     ///
     /// It will return an error if the provided `value` doesn't pass
-    /// [`Self::PROCESS`](Self::PROCESS).
-    fn set(&mut self, value: impl Into<Self::Inner>) -> Result<(), ConstructionError<Self>>;
+    /// [`Self::PROCESS`](Self::PROCESS). In this case, the wrapper is left
+    /// untouched.
+    fn try_set(&mut self, value: impl Into<Self::Inner>) -> Result<(), ConstructionError<Self>>;
+
+    /// Replace inner value with the provided one, panicking if it's invalid.
+    ///
+    /// It works like [`Self::try_set`](Self::try_set), but treats the invalid
+    /// `value` as a bug. The panic message is the [`Display`](fmt::Display) of
+    /// the [`ConstructionError`], so it contains the name of the wrapper and
+    /// the offending value:
+    /// ```should_panic
+    /// use prae::Wrapper;
+    ///
+    /// prae::define! {
+    ///     #[derive(Debug)]
+    ///     pub Username: String;
+    ///     ensure |u| !u.is_empty();
+    /// }
+    ///
+    /// let mut u = Username::new("user").unwrap();
+    /// // Panics with `failed to construct type Username from value "": value
+    /// // is invalid`.
+    /// u.set("");
+    /// ```
+    #[track_caller]
+    fn set(&mut self, value: impl Into<Self::Inner>)
+    where
+        Self::Inner: fmt::Debug,
+        Self::Error: fmt::Display,
+    {
+        if let Err(err) = self.try_set(value) {
+            panic!("{}", err);
+        }
+    }
+
+    /// Replace inner value with the provided one, returning the old value.
+    ///
+    /// It works like [`Self::try_set`](Self::try_set), but gives back the
+    /// previous inner value, just like [`core::mem::replace`]:
+    /// ```
+    /// use prae::Wrapper;
+    ///
+    /// prae::define! {
+    ///     #[derive(Debug)]
+    ///     pub State: String;
+    ///     ensure |s: &String| !s.is_empty();
+    /// }
+    ///
+    /// let mut state = State::new("idle").unwrap();
+    /// let old = state.replace("running").unwrap();
+    /// assert_eq!(old, "idle");
+    /// assert_eq!(state.get(), "running");
+    /// ```
+    /// It will return an error if the provided `value` doesn't pass
+    /// [`Self::PROCESS`](Self::PROCESS). In this case, the wrapper is left
+    /// untouched, and the error contains the rejected value.
+    fn replace(
+        &mut self,
+        value: impl Into<Self::Inner>,
+    ) -> Result<Self::Inner, ConstructionError<Self>> {
+        let mut value = value.into();
+        let result = Self::PROCESS_TRACED(&mut value).and_then(|()| {
+            Self::VALIDATE_TRANSITION(self.get(), &value).map_err(|original| {
+                let rejection = Rejection {
+                    phase: ProcessPhase::Validate,
+                    wrapper: Self::NAME,
+                };
+                (original, rejection)
+            })
+        });
+        match result {
+            Ok(()) => Ok(core::mem::replace(self.__get_mut(Token(())), value)),
+            Err((original, rejection)) => Err(ConstructionError {
+                original,
+                value,
+                rejection,
+            }),
+        }
+    }
 
     /// Mutate inner value using provided closure.
     ///
@@ -54,12 +491,380 @@ pub trait Wrapper: Sized {
     where
         Self::Inner: Clone,
     {
-        self.__mutate_with(Self::Inner::clone, f)
+        self.transact(f)
+    }
+
+    /// Mutate inner value using provided closure and return the result of the
+    /// closure.
+    ///
+    /// It works just like [`Self::mutate`](Self::mutate): the closure receives
+    /// a copy of the inner value, and, if the mutated value passes
+    /// [`Self::PROCESS`](Self::PROCESS), it replaces the inner value and the
+    /// result of the closure is returned. Otherwise, the wrapper is left
+    /// untouched, and the result of the closure is discarded:
+    /// ```
+    /// use prae::Wrapper;
+    ///
+    /// prae::define! {
+    ///     #[derive(Debug)]
+    ///     pub Stack: Vec<u32>;
+    ///     ensure |s: &Vec<u32>| !s.is_empty();
+    /// }
+    ///
+    /// let mut stack = Stack::new(vec![1, 2]).unwrap();
+    /// assert_eq!(stack.transact(|s| s.pop()).unwrap(), Some(2));
+    /// assert!(stack.transact(|s| s.pop()).is_err());
+    /// assert_eq!(stack.get(), &[1]);
+    /// ```
+    #[doc(alias = "try_mutate_map")]
+    fn transact<R>(
+        &mut self,
+        f: impl FnOnce(&mut Self::Inner) -> R,
+    ) -> Result<R, MutationError<Self>>
+    where
+        Self::Inner: Clone,
+    {
+        let mut result = None;
+        self.__mutate_with(Self::Inner::clone, |v| result = Some(f(v)))?;
+        match result {
+            Some(result) => Ok(result),
+            None => unreachable!("the closure is always called by `__mutate_with`"),
+        }
+    }
+
+    /// Mutate inner value in place using provided closure, without cloning
+    /// it.
+    ///
+    /// After the closure is executed, the inner value is passed through
+    /// [`Self::PROCESS`](Self::PROCESS), and the wrapper is returned back if
+    /// it passes. **Unlike [`Self::mutate`](Self::mutate), this method
+    /// doesn't roll back the failed mutation**: since there is no copy of the
+    /// old value, there is nothing to roll back to. Instead, the wrapper is
+    /// consumed, and the mutated (and possibly adjusted) value is moved into
+    /// the error, so an invalid value never stays inside a wrapper. It's the
+    /// tradeoff for not requiring [`Clone`](Clone), which is also why the
+    /// error carries only the new value, and why
+    /// [`Self::VALIDATE_TRANSITION`](Self::VALIDATE_TRANSITION) is not called.
+    ///
+    /// Use it for the huge or non-cloneable inner values:
+    /// ```
+    /// use prae::Wrapper;
+    ///
+    /// struct Document {
+    ///     title: String,
+    /// }
+    ///
+    /// prae::define! {
+    ///     ValidDocument: Document;
+    ///     ensure |d| !d.title.is_empty();
+    /// }
+    ///
+    /// let doc = ValidDocument::new(Document { title: "draft".to_owned() }).ok().unwrap();
+    /// let doc = doc.mutate_in_place(|d| d.title.push('!')).ok().unwrap();
+    /// assert_eq!(doc.get().title, "draft!");
+    ///
+    /// let err = doc.mutate_in_place(|d| d.title.clear()).err().unwrap();
+    /// assert!(err.old_value.is_none());
+    /// assert_eq!(err.new_value.title, "");
+    /// ```
+    fn mutate_in_place(
+        self,
+        f: impl FnOnce(&mut Self::Inner),
+    ) -> Result<Self, MutationError<Self>> {
+        let mut this = self;
+        f(this.__get_mut(Token(())));
+        match Self::PROCESS(this.__get_mut(Token(()))) {
+            Ok(()) => Ok(this),
+            Err(original) => Err(MutationError {
+                original,
+                old_value: None,
+                new_value: this.into_inner(),
+            }),
+        }
+    }
+
+    /// Remove consecutive duplicates from the inner vector using
+    /// [`Vec::dedup`] and validate the result.
+    ///
+    /// It works just like [`Self::mutate`](Self::mutate), so the wrapper is
+    /// left untouched if the deduplicated value doesn't pass
+    /// [`Self::PROCESS`](Self::PROCESS). If the wrapper must not contain any
+    /// duplicates, the deduplication can make an invalid value valid, so this
+    /// method is handy for repairing values that were constructed with
+    /// [`Self::new_unprocessed`](Self::new_unprocessed) or modified through
+    /// `as_inner_mut_unchecked`. If the `adjust` closure keeps the vector
+    /// sorted, all duplicates are consecutive and will be removed.
+    fn dedup_checked<T>(&mut self) -> Result<(), MutationError<Self>>
+    where
+        Self: Wrapper<Inner = Vec<T>>,
+        T: Clone + PartialEq,
+    {
+        self.mutate(|v| v.dedup())
+    }
+
+    /// Sort the inner vector using [`slice::sort_by_key`] and validate the
+    /// result.
+    ///
+    /// It works just like [`Self::mutate`](Self::mutate), so the wrapper is
+    /// left untouched if the sorted value doesn't pass
+    /// [`Self::PROCESS`](Self::PROCESS). Note that the `adjust` and
+    /// `canonicalize` closures are executed after the sorting, so if one of
+    /// them sorts the vector by another key, this order is lost.
+    fn sort_by_key_checked<T, K, F>(&mut self, f: F) -> Result<(), MutationError<Self>>
+    where
+        Self: Wrapper<Inner = Vec<T>>,
+        T: Clone,
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.mutate(|v| v.sort_by_key(f))
+    }
+
+    /// Extend the inner value with the provided items (e.g. merge another map
+    /// into the inner map) and validate the result.
+    ///
+    /// It works just like [`Self::mutate`](Self::mutate), so the wrapper is
+    /// left untouched if the merged value doesn't pass
+    /// [`Self::PROCESS`](Self::PROCESS):
+    /// ```
+    /// use std::collections::HashMap;
+    /// use prae::Wrapper;
+    ///
+    /// prae::define! {
+    ///     #[derive(Debug)]
+    ///     pub Config: HashMap<String, String>;
+    ///     ensure |c: &HashMap<String, String>| !c.contains_key("password");
+    /// }
+    ///
+    /// let mut config = Config::new([("host".to_owned(), "localhost".to_owned())]).unwrap();
+    /// config.merge_checked([("port".to_owned(), "8080".to_owned())]).unwrap();
+    /// assert!(config.merge_checked([("password".to_owned(), "1234".to_owned())]).is_err());
+    /// assert_eq!(config.get().len(), 2);
+    /// ```
+    fn merge_checked<I>(&mut self, other: I) -> Result<(), MutationError<Self>>
+    where
+        Self::Inner: Clone + Extend<I::Item>,
+        I: IntoIterator,
+    {
+        self.mutate(|v| v.extend(other))
+    }
+
+    /// Retain only the entries of the inner map specified by the predicate
+    /// using [`HashMap::retain`](std::collections::HashMap::retain) and
+    /// validate the result.
+    ///
+    /// It works just like [`Self::mutate`](Self::mutate), so the wrapper is
+    /// left untouched if the filtered map doesn't pass
+    /// [`Self::PROCESS`](Self::PROCESS).
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn retain_keys_checked<K, V, S, F>(&mut self, f: F) -> Result<(), MutationError<Self>>
+    where
+        Self: Wrapper<Inner = std::collections::HashMap<K, V, S>>,
+        K: Clone,
+        V: Clone,
+        S: Clone,
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.mutate(|m| m.retain(f))
+    }
+
+    /// Replace the element of the inner vector at the provided index and
+    /// return the old element.
+    ///
+    /// It will return [`ReplaceError::OutOfBounds`] if the index is out of
+    /// bounds, and [`ReplaceError::Mutation`] if the vector with the replaced
+    /// element doesn't pass [`Self::PROCESS`](Self::PROCESS). In both cases,
+    /// the wrapper is left untouched.
+    fn replace_index<T>(&mut self, index: usize, value: T) -> Result<T, ReplaceError<Self>>
+    where
+        Self: Wrapper<Inner = Vec<T>>,
+        T: Clone,
+    {
+        let len = self.get().len();
+        if index >= len {
+            return Err(ReplaceError::OutOfBounds { index, len });
+        }
+        let old = self.get()[index].clone();
+        self.mutate(|v| v[index] = value)
+            .map_err(ReplaceError::Mutation)?;
+        Ok(old)
+    }
+
+    /// Get a guarded mutable reference to the inner value.
+    ///
+    /// The returned [`GuardedMut`] allows to mutate the inner value in place,
+    /// without cloning it. When the guard is dropped, the inner value will be
+    /// passed through [`Self::PROCESS`](Self::PROCESS), so any adjustments will
+    /// persist. Since there is no previous value to fall back to, the guard
+    /// will panic if the mutated value doesn't pass the validation. If you
+    /// need to handle the error, use [`Self::mutate`](Self::mutate) instead.
+    ///
+    /// Unlike [`Self::as_inner_mut_unchecked`](Self::as_inner_mut_unchecked),
+    /// this method always validates the value.
+    fn get_mut(&mut self) -> GuardedMut<'_, Self> {
+        GuardedMut { wrapper: self }
+    }
+
+    /// Check if the inner value of the wrapper differs from the inner value of
+    /// the `other` wrapper.
+    ///
+    /// The wrapper can't know the fields of the inner type, so if you need a
+    /// field-by-field diff (e.g. for audit logging), compare the projections
+    /// of the inner values instead:
+    /// ```
+    /// use prae::Wrapper;
+    ///
+    /// #[derive(PartialEq)]
+    /// pub struct Config {
+    ///     host: String,
+    ///     port: u16,
+    /// }
+    ///
+    /// prae::define! {
+    ///     ValidConfig: Config;
+    ///     ensure |c| !c.host.is_empty();
+    /// }
+    ///
+    /// let old = ValidConfig::new(Config { host: "a".into(), port: 80 }).ok().unwrap();
+    /// let new = ValidConfig::new(Config { host: "a".into(), port: 81 }).ok().unwrap();
+    /// assert!(new.changed_from(&old));
+    /// assert!(new.get().host == old.get().host);
+    /// assert!(new.get().port != old.get().port);
+    /// ```
+    fn changed_from(&self, other: &Self) -> bool
+    where
+        Self::Inner: PartialEq,
+    {
+        self.get() != other.get()
+    }
+
+    /// Check if the inner value is equal to the default value of the inner
+    /// type.
+    ///
+    /// It's useful for omitting the default values during the serialization
+    /// (e.g. with `#[serde(skip_serializing_if = "Wrapper::is_default")]`) and
+    /// for the change detection. Note that the default value of the inner type
+    /// doesn't have to be valid for the wrapper, in which case this method
+    /// always returns `false`.
+    fn is_default(&self) -> bool
+    where
+        Self::Inner: Default + PartialEq,
+    {
+        *self.get() == Self::Inner::default()
+    }
+
+    /// Convert the inner value into a [`serde_json::Value`].
+    ///
+    /// It's a convenience for structured logging and other places that work
+    /// with JSON values instead of the typed data. It will return an error if
+    /// the inner value can't be represented as JSON (e.g. a map with non-string
+    /// keys).
+    #[cfg(feature = "serde_json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+    fn to_json_value(&self) -> Result<serde_json::Value, serde_json::Error>
+    where
+        Self::Inner: serde::Serialize,
+    {
+        serde_json::to_value(self.get())
+    }
+
+    /// Transform inner value using provided function.
+    ///
+    /// This is the owned analogue of [`Self::mutate`](Self::mutate). Since
+    /// this method consumes the wrapper, the inner value is moved into the
+    /// function without being cloned, and the old value doesn't need to be
+    /// preserved. This is why it's available for both `Clone` and `!Clone`
+    /// inner types.
+    ///
+    /// It will return an error if the transformed value doesn't pass
+    /// [`Self::PROCESS`](Self::PROCESS). The error contains the offending
+    /// value, just like with [`Self::new`](Self::new).
+    fn try_map(
+        self,
+        f: impl FnOnce(Self::Inner) -> Self::Inner,
+    ) -> Result<Self, ConstructionError<Self>> {
+        Self::new(f(self.into_inner()))
     }
 
     /// Unwrap the value into the inner type.
     fn into_inner(self) -> Self::Inner;
 
+    /// Convert the wrapper into another wrapper with the same inner type.
+    ///
+    /// The inner value is moved into [`W::new`](Self::new), so it's processed
+    /// by the target wrapper, and the conversion fails if it doesn't pass
+    /// the target's validation:
+    /// ```
+    /// use prae::Wrapper;
+    ///
+    /// prae::define! {
+    ///     #[derive(Debug)]
+    ///     pub Username: String;
+    ///     ensure |u: &String| !u.contains(' ');
+    /// }
+    ///
+    /// prae::define! {
+    ///     #[derive(Debug)]
+    ///     pub DisplayName: String;
+    ///     ensure |n: &String| n.len() <= 8;
+    /// }
+    ///
+    /// let name: DisplayName = Username::new("alice").unwrap().validate_into().unwrap();
+    /// assert_eq!(name.get(), "alice");
+    ///
+    /// let err = Username::new("bartholomew")
+    ///     .unwrap()
+    ///     .validate_into::<DisplayName>()
+    ///     .unwrap_err();
+    /// assert_eq!(err.value, "bartholomew");
+    /// ```
+    fn validate_into<W>(self) -> Result<W, ConstructionError<W>>
+    where
+        W: Wrapper<Inner = Self::Inner>,
+    {
+        W::new(self.into_inner())
+    }
+
+    /// Freeze the wrapper into a cheaply cloneable [`Frozen`] value.
+    ///
+    /// The inner value is moved into an [`Arc`](Arc), so cloning the frozen
+    /// value doesn't clone the inner value. [`Frozen`] provides only read
+    /// access to the inner value.
+    fn freeze(self) -> Frozen<Self> {
+        Frozen {
+            value: Arc::new(self.into_inner()),
+            wrapper: PhantomData,
+        }
+    }
+
+    /// Turn the wrapper into an unvalidated [`Draft`] of its inner value.
+    ///
+    /// The draft can be mutated freely and turned back into the wrapper with
+    /// [`Draft::build`], which validates the value.
+    fn into_draft(self) -> Draft<Self> {
+        Draft::new(self.into_inner())
+    }
+
+    /// Check out the inner value for a transactional mutation.
+    ///
+    /// The returned [`Checkout`] allows to mutate the inner value in place. To
+    /// keep the changes, call [`Checkout::commit`], which will pass the
+    /// mutated value through [`Self::PROCESS`](Self::PROCESS) and return an
+    /// error if it fails. If the commit fails, or if the checkout is dropped
+    /// without committing, the inner value will be reverted to the snapshot
+    /// taken by this method. This is why it's only available when the inner
+    /// type implements [`Clone`](Clone).
+    fn checkout(&mut self) -> Checkout<'_, Self>
+    where
+        Self::Inner: Clone,
+    {
+        Checkout {
+            snapshot: Some(self.get().clone()),
+            wrapper: self,
+        }
+    }
+
     /// This is a helper method that should be implemented in order for `mutate`
     /// method to work in a generic way. This method should not be used directly
     /// by the user (hence `#[doc(hidden)]` and a weird name).
@@ -79,26 +884,407 @@ pub trait Wrapper: Sized {
         unimplemented!()
     }
 
+    /// This is a helper method that should be implemented in order for
+    /// [`GuardedMut`] to work. This method can't be called by the user, since
+    /// only `prae` can construct the token (hence `#[doc(hidden)]` and a weird
+    /// name). Use [`Self::as_inner_mut_unchecked`](Self::as_inner_mut_unchecked)
+    /// instead:
+    /// ```compile_fail
+    /// use prae::Wrapper;
+    ///
+    /// prae::define! {
+    ///     #[derive(Debug)]
+    ///     pub Username: String;
+    /// }
+    ///
+    /// let mut u = Username::new("user").unwrap();
+    /// u.__get_mut(prae::__private::Token(())).clear();
+    /// ```
+    #[doc(hidden)]
+    fn __get_mut(&mut self, token: Token) -> &mut Self::Inner;
+
     /// Construct a new wrapper without calling
     /// [`Self::PROCESS`](Self::PROCESS).
+    ///
+    /// It's the only way to construct a wrapper without the validation. To
+    /// bypass the validation of an existing wrapper, use
+    /// [`Self::as_inner_mut_unchecked`](Self::as_inner_mut_unchecked).
     fn new_unprocessed(value: impl Into<Self::Inner>) -> Self;
 
     /// Replace inner value with the provided one without calling
     /// [`Self::PROCESS`](Self::PROCESS).
+    #[deprecated(
+        note = "assign the value through `as_inner_mut_unchecked` of the `unprocessed` feature instead"
+    )]
     fn set_unprocessed(&mut self, value: impl Into<Self::Inner>);
 
     /// Mutate inner value using provided closure without calling
     /// [`Self::PROCESS`](Self::PROCESS).
+    #[deprecated(note = "use `as_inner_mut_unchecked` of the `unprocessed` feature instead")]
     fn mutate_unprocessed(&mut self, f: impl FnOnce(&mut Self::Inner));
 
+    /// Get a mutable reference to the inner value without calling
+    /// [`Self::PROCESS`](Self::PROCESS), even in debug builds.
+    ///
+    /// This is **the** escape hatch for the cases when none of the checked
+    /// methods fit, e.g. when the inner value must be passed to an API that
+    /// fills it in place, or when it's mutated in a hot loop that can't afford
+    /// the validation on every step. It replaces
+    /// [`Self::set_unprocessed`](Self::set_unprocessed) and
+    /// [`Self::mutate_unprocessed`](Self::mutate_unprocessed), which are
+    /// deprecated. Only the construction has a separate escape hatch,
+    /// [`Self::new_unprocessed`](Self::new_unprocessed). Prefer the checked
+    /// methods, like [`Self::mutate`] and [`Self::get_mut`], whenever
+    /// possible.
+    ///
+    /// **Nothing stops you from breaking the invariants of the wrapper through
+    /// this reference.** Always call [`Self::renormalize`](Self::renormalize)
+    /// or [`Self::verify`](Self::verify) when you're done with it:
+    /// ```
+    /// # #[cfg(feature = "unprocessed")] {
+    /// use prae::Wrapper;
+    ///
+    /// prae::define! {
+    ///     #[derive(Debug)]
+    ///     pub Username: String;
+    ///     adjust |u| *u = u.trim().to_owned();
+    ///     ensure |u: &String| !u.is_empty();
+    /// }
+    ///
+    /// let mut u = Username::new("user").unwrap();
+    /// u.as_inner_mut_unchecked().push_str(" name ");
+    /// assert_eq!(u.get(), "user name ");
+    /// let u = u.verify().unwrap();
+    /// assert_eq!(u.get(), "user name");
+    /// # }
+    /// ```
+    #[cfg(feature = "unprocessed")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unprocessed")))]
+    fn as_inner_mut_unchecked(&mut self) -> &mut Self::Inner {
+        self.__get_mut(Token(()))
+    }
+
     /// Verify that inner value still passes [`Self::PROCESS`](Self::PROCESS).
+    ///
+    /// This method doesn't depend on the `unprocessed` feature, so it can be
+    /// used to re-check the values that were created through a path that
+    /// bypasses the validation (e.g. a `#[derive(Deserialize)]` of a legacy
+    /// format). The value is returned back if it's valid; otherwise, the
+    /// returned error contains the offending value.
     fn verify(self) -> Result<Self, VerificationError<Self>>;
+
+    /// Run [`Self::PROCESS`](Self::PROCESS) on the inner value in place.
+    ///
+    /// Unlike [`Self::verify`](Self::verify), this method doesn't consume the
+    /// wrapper. It's meant to fix up the value after the unchecked mutations
+    /// through `as_inner_mut_unchecked` (under the `unprocessed` feature):
+    /// the value is adjusted towards it's canonical form (e.g.
+    /// trimmed), and the validation error is returned if it's still invalid.
+    /// Note that in this case the inner value stays invalid, just like it was
+    /// before the call.
+    fn renormalize(&mut self) -> Result<(), Self::Error> {
+        Self::PROCESS(self.__get_mut(Token(())))
+    }
+}
+
+/// A guard that provides mutable access to the inner value of the wrapper. It
+/// is returned by the [`Wrapper::get_mut`](crate::Wrapper::get_mut) method.
+///
+/// When dropped, the guard passes the inner value through
+/// [`Wrapper::PROCESS`](crate::Wrapper::PROCESS) and panics if it fails.
+pub struct GuardedMut<'a, W: Wrapper> {
+    wrapper: &'a mut W,
+}
+
+impl<W: Wrapper> Deref for GuardedMut<'_, W> {
+    type Target = W::Inner;
+    fn deref(&self) -> &Self::Target {
+        self.wrapper.get()
+    }
+}
+
+impl<W: Wrapper> DerefMut for GuardedMut<'_, W> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.wrapper.__get_mut(Token(()))
+    }
+}
+
+impl<W: Wrapper> Drop for GuardedMut<'_, W> {
+    fn drop(&mut self) {
+        // Panicking while already panicking would abort the process. Without
+        // `std`, there is no way to check it, so `no_std` users get the abort.
+        #[cfg(feature = "std")]
+        let panicking = std::thread::panicking();
+        #[cfg(not(feature = "std"))]
+        let panicking = false;
+        if W::PROCESS(self.wrapper.__get_mut(Token(()))).is_err() && !panicking {
+            panic!(
+                "value of type {} is invalid after mutation through `get_mut`",
+                W::NAME
+            );
+        }
+    }
+}
+
+/// A guard that provides transactional mutable access to the inner value of the
+/// wrapper. It is returned by the [`Wrapper::checkout`](crate::Wrapper::checkout)
+/// method.
+///
+/// The changes are kept only after a successful [`Checkout::commit`]. If the
+/// guard is dropped without committing, the inner value is reverted.
+pub struct Checkout<'a, W: Wrapper> {
+    wrapper: &'a mut W,
+    snapshot: Option<W::Inner>,
+}
+
+impl<W: Wrapper> Checkout<'_, W>
+where
+    W::Inner: Clone,
+{
+    /// Commit the changes made to the inner value.
+    ///
+    /// It will return an error if the mutated value doesn't pass
+    /// [`Wrapper::PROCESS`](crate::Wrapper::PROCESS). In this case, the inner
+    /// value will be reverted to the state it had before the checkout.
+    pub fn commit(mut self) -> Result<(), MutationError<W>> {
+        let snapshot = self.snapshot.take().expect("snapshot is taken only once");
+        let value = self.wrapper.__get_mut(Token(()));
+        match W::PROCESS(value).and_then(|()| W::VALIDATE_TRANSITION(&snapshot, value)) {
+            Ok(()) => Ok(()),
+            Err(original) => Err(MutationError {
+                original,
+                old_value: Some(snapshot.clone()),
+                new_value: core::mem::replace(value, snapshot),
+            }),
+        }
+    }
+}
+
+impl<W: Wrapper> Deref for Checkout<'_, W> {
+    type Target = W::Inner;
+    fn deref(&self) -> &Self::Target {
+        self.wrapper.get()
+    }
+}
+
+impl<W: Wrapper> DerefMut for Checkout<'_, W> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.wrapper.__get_mut(Token(()))
+    }
+}
+
+impl<W: Wrapper> Drop for Checkout<'_, W> {
+    fn drop(&mut self) {
+        if let Some(snapshot) = self.snapshot.take() {
+            *self.wrapper.__get_mut(Token(())) = snapshot;
+        }
+    }
+}
+
+/// An immutable, cheaply cloneable view of the wrapper's inner value. It is
+/// returned by the [`Wrapper::freeze`](crate::Wrapper::freeze) method.
+///
+/// The inner value is stored in an [`Arc`](Arc), so the frozen value can be
+/// shared across many readers without cloning the inner value.
+pub struct Frozen<W: Wrapper> {
+    value: Arc<W::Inner>,
+    wrapper: PhantomData<W>,
+}
+
+impl<W: Wrapper> Frozen<W> {
+    /// Get a shared reference to the inner value.
+    pub fn get(&self) -> &W::Inner {
+        &self.value
+    }
+}
+
+impl<W: Wrapper> Clone for Frozen<W> {
+    fn clone(&self) -> Self {
+        Self {
+            value: Arc::clone(&self.value),
+            wrapper: PhantomData,
+        }
+    }
+}
+
+impl<W: Wrapper> Deref for Frozen<W> {
+    type Target = W::Inner;
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<W> fmt::Debug for Frozen<W>
+where
+    W: Wrapper,
+    W::Inner: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Frozen").field(&self.value).finish()
+    }
+}
+
+/// An unvalidated draft of the wrapper's inner value. It is returned by the
+/// [`Wrapper::into_draft`](crate::Wrapper::into_draft) method, and can also be
+/// created with [`Draft::new`] or [`Default::default`].
+///
+/// The draft can be mutated freely, since the value is validated only once,
+/// when [`Draft::build`] is called. It's useful for accumulating the user input
+/// (e.g. the fields of a form) that is allowed to be invalid until it's
+/// submitted:
+/// ```
+/// use prae::{Draft, Wrapper};
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Username: String;
+///     adjust |u| *u = u.trim().to_owned();
+///     ensure |u: &String| !u.is_empty();
+/// }
+///
+/// pub type UsernameDraft = Draft<Username>;
+///
+/// let mut draft = UsernameDraft::default();
+/// draft.push_str("  user");
+/// let username = draft.build().unwrap();
+/// assert_eq!(username.get(), "user");
+/// ```
+pub struct Draft<W: Wrapper> {
+    value: W::Inner,
+    wrapper: PhantomData<W>,
+}
+
+impl<W: Wrapper> Draft<W> {
+    /// Create a new draft from the provided `value` without validating it.
+    pub fn new(value: impl Into<W::Inner>) -> Self {
+        Self {
+            value: value.into(),
+            wrapper: PhantomData,
+        }
+    }
+
+    /// Get a shared reference to the inner value.
+    pub fn get(&self) -> &W::Inner {
+        &self.value
+    }
+
+    /// Get a mutable reference to the inner value.
+    pub fn get_mut(&mut self) -> &mut W::Inner {
+        &mut self.value
+    }
+
+    /// Unwrap the draft into the inner value.
+    pub fn into_inner(self) -> W::Inner {
+        self.value
+    }
+
+    /// Construct the wrapper from the drafted value.
+    ///
+    /// It will return an error if the value doesn't pass
+    /// [`Wrapper::PROCESS`](crate::Wrapper::PROCESS).
+    pub fn build(self) -> Result<W, ConstructionError<W>> {
+        W::new(self.value)
+    }
+}
+
+impl<W> Default for Draft<W>
+where
+    W: Wrapper,
+    W::Inner: Default,
+{
+    fn default() -> Self {
+        Self::new(W::Inner::default())
+    }
+}
+
+impl<W> Clone for Draft<W>
+where
+    W: Wrapper,
+    W::Inner: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<W: Wrapper> Deref for Draft<W> {
+    type Target = W::Inner;
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<W: Wrapper> DerefMut for Draft<W> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<W> fmt::Debug for Draft<W>
+where
+    W: Wrapper,
+    W::Inner: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Draft").field(&self.value).finish()
+    }
+}
+
+/// The phase of [`Wrapper::PROCESS`](crate::Wrapper::PROCESS) function that
+/// has rejected the value. See
+/// [`ConstructionError::phase`](ConstructionError::phase).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProcessPhase {
+    /// The value was rejected by the fallible `adjust` closure of the wrapper
+    /// itself.
+    Adjust,
+    /// The value was rejected by the wrapper that was extended with
+    /// [`extend!`](crate::extend).
+    Extend,
+    /// The value was rejected by the validation closures of the wrapper
+    /// itself (including `validate_transition` and `validate_async`).
+    Validate,
+}
+
+/// Tells where the value was rejected. It's returned along with the original
+/// error by the
+/// [`Wrapper::PROCESS_TRACED`](crate::Wrapper::PROCESS_TRACED) function and
+/// kept in the [`ConstructionError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rejection {
+    /// The phase that has rejected the value.
+    pub phase: ProcessPhase,
+    /// The [`NAME`](crate::Wrapper::NAME) of the wrapper that has rejected the
+    /// value. For the chains of [`extend!`](crate::extend) wrappers, it's the
+    /// name of the deepest wrapper that has rejected it.
+    pub wrapper: &'static str,
+}
+
+/// Formats the inner value in the errors, respecting
+/// [`Wrapper::REDACT_VALUE`](crate::Wrapper::REDACT_VALUE) and
+/// [`Wrapper::FMT_VALUE`](crate::Wrapper::FMT_VALUE).
+struct DisplayValue<'a, W: Wrapper>(&'a W::Inner);
+
+impl<W> fmt::Debug for DisplayValue<'_, W>
+where
+    W: Wrapper,
+    W::Inner: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if W::REDACT_VALUE {
+            f.write_str("<redacted>")
+        } else if let Some(fmt_value) = W::FMT_VALUE {
+            fmt_value(self.0, f)
+        } else {
+            self.0.fmt(f)
+        }
+    }
 }
 
 /// A wrapper-error that will be returned if the
 /// [`Wrapper::new`](crate::Wrapper::new) or
-/// [`Wrapper::set`](crate::Wrapper::set) methods receive a value that doesn't
-/// pass [`Wrapper::PROCESS`](crate::Wrapper::PROCESS) function.
+/// [`Wrapper::try_set`](crate::Wrapper::try_set) methods receive a value that
+/// doesn't pass [`Wrapper::PROCESS`](crate::Wrapper::PROCESS) function.
 ///
 /// This wrapper contains both the value that caused the error and the original
 /// error returned by the [`Wrapper::PROCESS`](crate::Wrapper::PROCESS)
@@ -109,6 +1295,8 @@ pub struct ConstructionError<W: Wrapper> {
     pub value: W::Inner,
     /// Original error.
     pub original: W::Error,
+    /// Where the value was rejected.
+    pub rejection: Rejection,
 }
 
 impl<W> fmt::Display for ConstructionError<W>
@@ -122,7 +1310,7 @@ where
             f,
             "failed to construct type {} from value {:?}: {}",
             W::NAME,
-            self.value,
+            DisplayValue::<W>(&self.value),
             self.original,
         )
     }
@@ -137,23 +1325,108 @@ where
     // NOTE: `self.error` could be used for `source` function.
     // However, it would require `W::Error: Error + 'static`,
     // which is more restrictive, therefore less appealing.
-    // It's also not clear for me if this change would be
-    // useful.
+    // Use `inner_source` instead if your error type satisfies it.
     // Waiting for the stabilization of specialization?
 }
 
+impl<W: Wrapper> ConstructionError<W> {
+    /// Get the phase of [`Wrapper::PROCESS`](crate::Wrapper::PROCESS) function
+    /// that has rejected the value.
+    ///
+    /// It's recorded by [`Wrapper::PROCESS_TRACED`](crate::Wrapper::PROCESS_TRACED)
+    /// when the value is rejected, so the value isn't validated again. It's
+    /// useful for debugging the chains of [`extend!`](crate::extend)
+    /// wrappers:
+    /// ```
+    /// use prae::{ProcessPhase, Wrapper};
+    ///
+    /// prae::define! {
+    ///     #[derive(Debug)]
+    ///     pub Text: String;
+    ///     ensure |t: &String| !t.is_empty();
+    /// }
+    ///
+    /// prae::extend! {
+    ///     #[derive(Debug)]
+    ///     pub Word: Text;
+    ///     ensure |w: &String| !w.contains(' ');
+    /// }
+    ///
+    /// let err = Word::new("").unwrap_err();
+    /// assert_eq!(err.phase(), ProcessPhase::Extend);
+    /// let err = Word::new("two words").unwrap_err();
+    /// assert_eq!(err.phase(), ProcessPhase::Validate);
+    /// ```
+    pub fn phase(&self) -> ProcessPhase {
+        self.rejection.phase
+    }
+
+    /// Get the [`NAME`](crate::Wrapper::NAME) of the wrapper that has rejected
+    /// the value.
+    ///
+    /// It's recorded along with the [`phase`](Self::phase), but unlike it,
+    /// names the rejecting wrapper in the whole chain of
+    /// [`extend!`](crate::extend) wrappers:
+    /// ```
+    /// use prae::Wrapper;
+    ///
+    /// prae::define! {
+    ///     #[derive(Debug)]
+    ///     pub Text: String;
+    ///     ensure |t: &String| !t.is_empty();
+    /// }
+    ///
+    /// prae::extend! {
+    ///     #[derive(Debug)]
+    ///     pub CapText: Text;
+    ///     ensure |t: &String| t.starts_with(char::is_uppercase);
+    /// }
+    ///
+    /// prae::extend! {
+    ///     #[derive(Debug)]
+    ///     pub Sentence: CapText;
+    ///     ensure |s: &String| s.ends_with('.');
+    /// }
+    ///
+    /// assert_eq!(Sentence::new("").unwrap_err().failed_wrapper(), "Text");
+    /// assert_eq!(Sentence::new("hi.").unwrap_err().failed_wrapper(), "CapText");
+    /// assert_eq!(Sentence::new("Hi").unwrap_err().failed_wrapper(), "Sentence");
+    /// ```
+    pub fn failed_wrapper(&self) -> &'static str {
+        self.rejection.wrapper
+    }
+}
+
+impl<W> ConstructionError<W>
+where
+    W: Wrapper,
+    W::Error: Error + 'static,
+{
+    /// Get the original error as the source of this error.
+    ///
+    /// The [`Error`] implementation of this type doesn't provide
+    /// [`Error::source`], because it would require the original error to
+    /// implement [`Error`]. Use this method to continue the error chain when
+    /// it does.
+    pub fn inner_source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.original)
+    }
+}
+
 /// A wrapper-error that will be returned if the
 /// [`Wrapper::mutate`](crate::Wrapper::mutate) method receives a closure that
 /// mutates the inner value in such a way that it no longer passes
 /// the [`Wrapper::PROCESS`](crate::Wrapper::PROCESS) function.
 ///
 /// This wrapper contains the mutated value that caused the error, the
-/// value before the mutation and the original error returned by
-/// [`Wrapper::PROCESS`](crate::Wrapper::PROCESS) function.
+/// value before the mutation (unless the method mutated it in place, like
+/// [`Wrapper::mutate_in_place`](crate::Wrapper::mutate_in_place)) and the
+/// original error returned by [`Wrapper::PROCESS`](crate::Wrapper::PROCESS)
+/// function.
 #[derive(Debug)]
 pub struct MutationError<W: Wrapper> {
-    /// Value before the mutation.
-    pub old_value: W::Inner,
+    /// Value before the mutation, if it was kept.
+    pub old_value: Option<W::Inner>,
     /// Value after mutation (the cause of the error).
     pub new_value: W::Inner,
     /// Original error.
@@ -167,12 +1440,14 @@ where
     W::Error: fmt::Display,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to mutate type {} ", W::NAME)?;
+        if let Some(old_value) = &self.old_value {
+            write!(f, "from value {:?} ", DisplayValue::<W>(old_value))?;
+        }
         write!(
             f,
-            "failed to mutate type {} from value {:?} to value {:?}: {}",
-            W::NAME,
-            self.old_value,
-            self.new_value,
+            "to value {:?}: {}",
+            DisplayValue::<W>(&self.new_value),
             self.original,
         )
     }
@@ -186,6 +1461,20 @@ where
 {
 }
 
+impl<W> MutationError<W>
+where
+    W: Wrapper,
+    W::Error: Error + 'static,
+{
+    /// Get the original error as the source of this error.
+    ///
+    /// See [`ConstructionError::inner_source`] for why this isn't
+    /// [`Error::source`].
+    pub fn inner_source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.original)
+    }
+}
+
 /// A wrapper-error that will be returned if the
 /// [`Wrapper::verify`](crate::Wrapper::verify) method is called on a wrapper
 /// whose inner value no longer passes the
@@ -213,7 +1502,7 @@ where
             f,
             "verification of type {} with value {:?} failed: {}",
             W::NAME,
-            self.value,
+            DisplayValue::<W>(&self.value),
             self.original,
         )
     }
@@ -227,11 +1516,301 @@ where
 {
 }
 
+impl<W> VerificationError<W>
+where
+    W: Wrapper,
+    W::Error: Error + 'static,
+{
+    /// Get the original error as the source of this error.
+    ///
+    /// See [`ConstructionError::inner_source`] for why this isn't
+    /// [`Error::source`].
+    pub fn inner_source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.original)
+    }
+}
+
+/// A wrapper-error that will be returned by the
+/// [`Wrapper::new_retaining_input`](crate::Wrapper::new_retaining_input)
+/// method.
+///
+/// This wrapper contains the value as it was provided to the method, along
+/// with the [`ConstructionError`] that contains the adjusted value.
+pub struct RetainedInputError<W: Wrapper> {
+    /// Value as it was provided, before the adjustment.
+    pub input: W::Inner,
+    /// Original construction error.
+    pub error: ConstructionError<W>,
+}
+
+impl<W> fmt::Debug for RetainedInputError<W>
+where
+    W: Wrapper + fmt::Debug,
+    W::Inner: fmt::Debug,
+    W::Error: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetainedInputError")
+            .field("input", &self.input)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl<W> fmt::Display for RetainedInputError<W>
+where
+    W: Wrapper,
+    W::Inner: fmt::Debug,
+    W::Error: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl<W> Error for RetainedInputError<W>
+where
+    W: Wrapper + fmt::Debug,
+    W::Inner: fmt::Debug,
+    W::Error: fmt::Display + fmt::Debug,
+{
+}
+
+/// An error that will be returned by the
+/// [`Wrapper::try_new`](crate::Wrapper::try_new) method.
+pub enum TryNewError<W, V>
+where
+    W: Wrapper,
+    W::Inner: TryFrom<V>,
+{
+    /// The value can't be converted into the inner type.
+    Conversion(<W::Inner as TryFrom<V>>::Error),
+    /// The converted value doesn't pass
+    /// [`Wrapper::PROCESS`](crate::Wrapper::PROCESS) function.
+    Construction(ConstructionError<W>),
+}
+
+impl<W, V> fmt::Debug for TryNewError<W, V>
+where
+    W: Wrapper + fmt::Debug,
+    W::Inner: TryFrom<V> + fmt::Debug,
+    W::Error: fmt::Debug,
+    <W::Inner as TryFrom<V>>::Error: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Conversion(err) => f.debug_tuple("Conversion").field(err).finish(),
+            Self::Construction(err) => f.debug_tuple("Construction").field(err).finish(),
+        }
+    }
+}
+
+impl<W, V> fmt::Display for TryNewError<W, V>
+where
+    W: Wrapper,
+    W::Inner: TryFrom<V> + fmt::Debug,
+    W::Error: fmt::Display,
+    <W::Inner as TryFrom<V>>::Error: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Conversion(err) => write!(
+                f,
+                "failed to convert value to the inner type of {}: {}",
+                W::NAME,
+                err
+            ),
+            Self::Construction(err) => err.fmt(f),
+        }
+    }
+}
+
+impl<W, V> Error for TryNewError<W, V>
+where
+    W: Wrapper + fmt::Debug,
+    W::Inner: TryFrom<V> + fmt::Debug,
+    W::Error: fmt::Display + fmt::Debug,
+    <W::Inner as TryFrom<V>>::Error: fmt::Display + fmt::Debug,
+{
+}
+
+/// An error that will be returned by the
+/// [`Wrapper::replace_index`](crate::Wrapper::replace_index) method.
+pub enum ReplaceError<W: Wrapper> {
+    /// The index is out of bounds of the inner vector.
+    OutOfBounds {
+        /// The provided index.
+        index: usize,
+        /// The length of the inner vector.
+        len: usize,
+    },
+    /// The vector with the replaced element doesn't pass
+    /// [`Wrapper::PROCESS`](crate::Wrapper::PROCESS) function.
+    Mutation(MutationError<W>),
+}
+
+impl<W> fmt::Debug for ReplaceError<W>
+where
+    W: Wrapper + fmt::Debug,
+    W::Inner: fmt::Debug,
+    W::Error: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds { index, len } => f
+                .debug_struct("OutOfBounds")
+                .field("index", index)
+                .field("len", len)
+                .finish(),
+            Self::Mutation(err) => f.debug_tuple("Mutation").field(err).finish(),
+        }
+    }
+}
+
+impl<W> fmt::Display for ReplaceError<W>
+where
+    W: Wrapper,
+    W::Inner: fmt::Debug,
+    W::Error: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds { index, len } => write!(
+                f,
+                "failed to replace element of type {} at index {}: length is {}",
+                W::NAME,
+                index,
+                len,
+            ),
+            Self::Mutation(err) => err.fmt(f),
+        }
+    }
+}
+
+impl<W> Error for ReplaceError<W>
+where
+    W: Wrapper + fmt::Debug,
+    W::Inner: fmt::Debug,
+    W::Error: fmt::Display + fmt::Debug,
+{
+}
+
+/// An error with the location of its cause in the source (e.g. the range of
+/// bytes it occupies). It will be returned by the
+/// [`Wrapper::new_spanned`](crate::Wrapper::new_spanned) method.
+///
+/// The [`fmt::Display`] implementation prints the error followed by the span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spanned<E, S = Range<usize>> {
+    /// The location of the cause of the error.
+    pub span: S,
+    /// Original error.
+    pub error: E,
+}
+
+impl<E, S> fmt::Display for Spanned<E, S>
+where
+    E: fmt::Display,
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at {:?})", self.error, self.span)
+    }
+}
+
+impl<E, S> Error for Spanned<E, S>
+where
+    E: Error + 'static,
+    S: fmt::Debug,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// An error of the `ensure_detailed` closure of [`define!`](crate::define).
+///
+/// Unlike the `&'static str` of the regular `ensure` closure, it also contains
+/// a [`Debug`](fmt::Debug) snapshot of the rejected value, so the context is
+/// not lost when only the original error is propagated.
+///
+/// The [`fmt::Display`] implementation prints the message followed by the
+/// value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnsureError {
+    /// The message of the `ensure_detailed` closure.
+    pub message: &'static str,
+    /// The [`Debug`](fmt::Debug) output of the rejected value. It will be
+    /// `<redacted>` if [`Wrapper::REDACT_VALUE`] is set.
+    pub value: String,
+}
+
+impl EnsureError {
+    /// Construct a new error with the snapshot of the `value` of the wrapper
+    /// `W`.
+    pub fn new<W>(message: &'static str, value: &W::Inner) -> Self
+    where
+        W: Wrapper,
+        W::Inner: fmt::Debug,
+    {
+        Self {
+            message,
+            value: format!("{:?}", DisplayValue::<W>(value)),
+        }
+    }
+}
+
+impl fmt::Display for EnsureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (got {})", self.message, self.value)
+    }
+}
+
+impl Error for EnsureError {}
+
 /// Convenience trait that allows mapping from `Result<_,
 /// ConstructionError<Wrapper>>`, `Result<_, MutationError<Wrapper>` and
 /// `Result<_, VerificationError<Wrapper>>` to `Result<_, Wrapper::Error>`.
-pub trait MapOriginalError<O, E> {
+pub trait MapOriginalError<O, E>: Sized {
     fn map_original(self) -> Result<O, E>;
+
+    /// Map the error to the original error and convert it into `T`.
+    ///
+    /// This is useful when you have a domain error type that can be
+    /// constructed from the errors of multiple wrappers:
+    ///
+    /// ```
+    /// use prae::{MapOriginalError, Wrapper};
+    ///
+    /// prae::define! {
+    ///     #[derive(Debug)]
+    ///     Username: String;
+    ///     ensure |u: &String| !u.is_empty();
+    /// }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum AppError {
+    ///     InvalidUsername(&'static str),
+    /// }
+    ///
+    /// impl From<&'static str> for AppError {
+    ///     fn from(err: &'static str) -> Self {
+    ///         Self::InvalidUsername(err)
+    ///     }
+    /// }
+    ///
+    /// fn register(name: &str) -> Result<Username, AppError> {
+    ///     Username::new(name).err_into()
+    /// }
+    ///
+    /// assert_eq!(
+    ///     register("").unwrap_err(),
+    ///     AppError::InvalidUsername("value is invalid")
+    /// );
+    /// ```
+    fn err_into<T: From<E>>(self) -> Result<O, T> {
+        self.map_original().map_err(T::from)
+    }
 }
 
 impl<O, W: Wrapper> MapOriginalError<O, W::Error> for Result<O, ConstructionError<W>> {
@@ -251,3 +1830,34 @@ impl<O, W: Wrapper> MapOriginalError<O, W::Error> for Result<O, VerificationErro
         self.map_err(|err| err.original)
     }
 }
+
+/// Construct wrappers from the provided values in parallel using
+/// [`rayon`](https://docs.rs/rayon).
+///
+/// It's useful when you need to validate a lot of values and the validation of
+/// each value is expensive. The order of the constructed wrappers matches the
+/// order of the provided values.
+///
+/// If some values don't pass [`Wrapper::PROCESS`](Wrapper::PROCESS), the error
+/// of the value with the lowest index is returned along with this index. The
+/// reported failure is deterministic and doesn't depend on the scheduling of
+/// the threads, but all of the values will be processed even if some of them
+/// fail.
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub fn par_try_collect<W, V>(values: Vec<V>) -> Result<Vec<W>, (usize, ConstructionError<W>)>
+where
+    W: Wrapper + Send,
+    W::Inner: Send,
+    W::Error: Send,
+    V: Into<W::Inner> + Send,
+{
+    use rayon::prelude::*;
+
+    let results: Vec<_> = values.into_par_iter().map(W::new).collect();
+    results
+        .into_iter()
+        .enumerate()
+        .map(|(i, result)| result.map_err(|err| (i, err)))
+        .collect()
+}
@@ -1,4 +1,5 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //! `prae` is a crate that aims to provide a better way to define types that
 //! require validation.
@@ -86,9 +87,10 @@
 //! If you find yourself in a situation where the internal adjustment and
 //! validation of your type becomes a performance bottleneck (for example, you
 //! perform a heavy validation and mutate your type in a hot loop) - try
-//! `_unprocessed` variants of [`Wrapper`] methods. They won't call
-//! [`Wrapper::PROCESS`]. However, I strongly advise you to call
-//! [`Wrapper::verify`] after such operations.
+//! [`Wrapper::new_unprocessed`] and [`Wrapper::as_inner_mut_unchecked`] (under
+//! the `unprocessed` feature). They won't call [`Wrapper::PROCESS`]. However, I
+//! strongly advise you to call [`Wrapper::verify`] or [`Wrapper::renormalize`]
+//! after such operations.
 //!
 //! # Feature flags
 //!
@@ -96,7 +98,16 @@
 //!
 //!  Name | Description
 //!  ---|---
-//!  `serde` | Adds the [`impl_serde`] plugin.
+//!  `std` | Enabled by default. Disable it to use the crate in `no_std` environments with `alloc`.
+//!  `serde` | Adds the [`impl_serde`] and [`impl_serde_with_error`] plugins, the `validated_serde` flag and the [`deserialize_vec`] function.
+//!  `serde_json` | Enables `serde` and adds the [`impl_serde_lenient`] plugin and the [`Wrapper::to_json_value`] method.
+//!  `unprocessed` | Adds the [`Wrapper::as_inner_mut_unchecked`] escape hatch.
+//!  `hex` | Adds the [`impl_hex`] plugin.
+//!  `ts-rs` | Adds the [`impl_ts`] plugin.
+//!  `arbitrary` | Adds the [`impl_arbitrary`] plugin.
+//!  `rayon` | Adds the [`par_try_collect`] function.
+//!  `regex` | Adds the `ensure_matches_any` and `ensure_matches_none` arguments to [`define!`] and the [`matches!`] macro.
+//!  `derive` | Adds the [`Validate`] derive macro, an attribute based alternative to [`define!`].
 //!
 //! # Credits
 //! This crate was highly inspired by the
@@ -104,9 +115,59 @@
 //! just a fork of tightness with a slightly different philosophy.
 //! See [this](https://github.com/PabloMansanet/tightness/issues/2) issue for details.
 
+extern crate alloc;
+
+pub mod adjust;
 mod core;
 mod plugins;
+pub mod rules;
 pub use crate::core::*;
+#[cfg(feature = "hex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hex")))]
+pub use crate::plugins::hex::FromHexError;
+#[cfg(feature = "serde")]
+pub use crate::plugins::serde::deserialize_vec;
+pub use crate::plugins::std::FromStrError;
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use prae_derive::Validate;
+
+#[doc(hidden)]
+pub mod __private {
+    /// A token that only this crate can construct, so that the methods that
+    /// take it can't be called outside of it.
+    pub struct Token(pub(crate) ());
+
+    pub use alloc::string::String;
+    pub use alloc::vec::Vec;
+    #[cfg(feature = "regex")]
+    pub use regex;
+    #[cfg(feature = "std")]
+    pub use std::sync::OnceLock;
+
+    #[cfg(feature = "serde_json")]
+    pub use crate::plugins::serde::deserialize_lenient;
+    pub use crate::plugins::std::{
+        fmt_debug_truncated, fmt_hex_truncated, fmt_truncated, StringInner,
+    };
+
+    /// Emit a warning about the derived `Deserialize`, since macros can't
+    /// emit warnings directly.
+    #[deprecated(
+        note = "`#[derive(Deserialize)]` skips the validation of the wrapper, use `validated_serde` instead"
+    )]
+    pub const fn derive_deserialize() {}
+
+    /// Fix the argument type of a key closure passed to a plugin, so it
+    /// doesn't need to be annotated.
+    pub fn key_fn<W, K, F>(f: F) -> F
+    where
+        W: crate::Wrapper,
+        F: Fn(&W::Inner) -> K,
+    {
+        f
+    }
+}
 
 /// Convenience macro that creates a
 /// [`Newtype`](https://rust-unofficial.github.io/patterns/patterns/behavioural/newtype.html)
@@ -117,26 +178,83 @@ pub use crate::core::*;
 /// - The `Newtype` struct;
 /// - The implementation of the [`Wrapper`] for the struct;
 /// - The implementation of the [`AsRef`](AsRef);
-/// [`Borrow`](::core::borrow::Borrow),
-/// [`TryFrom`](TryFrom) and [`From`](From) traits for the struct.
+///   [`Borrow`](::core::borrow::Borrow),
+///   [`TryFrom`](TryFrom) and [`From`](From) traits for the struct.
 ///
 /// However, the generated code can be extended in using two methods:
 /// - Attribute macros attached to the type signature (e.g. `#[derive(Debug)]`);
 /// - Type plugins specified in the end of the macro.
 ///
+/// Doc comments above the type signature are attached to the struct. The
+/// methods of [`Wrapper`] are documented by the trait itself, and the inherent
+/// methods generated by the arguments (e.g. `new_const` or `in_range`) get doc
+/// comments that mention the name of the wrapper, so the generated API is
+/// documented when the wrapper is a part of the public API of your crate.
+///
 /// It is worth noting that the inner value of created `Newtype` struct can be
 /// accessed from the code in the same module. To fully protect this value from
-/// being accessed directly, put your type in a separate module.
+/// being accessed directly, put your type in a separate module. The
+/// visibility of the inner field can be set in the
+/// [type signature](#type-signature).
 ///
 /// # Macro structure
 ///
 /// Table of contents:
 /// - [Type signature](#type-signature)
 /// - [`adjust` closure](#adjust-closure)
+/// - [`canonicalize` closure](#canonicalize-closure)
 /// - [`ensure` closure](#ensure-closure)
+/// - [`forbid` closure](#forbid-closure)
+/// - [`ensure_detailed` closure](#ensure_detailed-closure)
+/// - [`ensure const` closure](#ensure-const-closure)
+/// - [`ensure_enum` checks](#ensure_enum-checks)
+/// - [`ensure_range` bounds](#ensure_range-bounds)
+/// - [`ensure_matches_any` and `ensure_matches_none`
+///   patterns](#ensure_matches_any-and-ensure_matches_none-patterns)
 /// - [`validate` closure](#validate-closure)
+/// - [`repair` closure](#repair-closure)
+/// - [`validate_async` closure](#validate_async-closure)
+/// - [`validate_transition` closure](#validate_transition-closure)
+/// - [`skippable` closure](#skippable-closure)
+/// - [`validated_serde` flag](#validated_serde-flag)
+/// - [`redact_value` flag](#redact_value-flag)
+/// - [`truncate_value` flag](#truncate_value-flag)
+/// - [`forward` methods](#forward-methods)
 /// - [Plugins](#plugins)
 ///
+/// All of the closures are stored in the constants of [`Wrapper`] as function
+/// pointers, so they can't capture variables from the surrounding scope. The
+/// compiler reports it as an "attempt to use a non-constant value in a
+/// constant":
+/// ```compile_fail
+/// let max_len = 16;
+/// prae::define! {
+///     pub Username: String;
+///     ensure |u: &String| u.len() <= max_len;
+/// }
+/// ```
+/// Use a `const`, a `static` or a function instead. For a value that is only
+/// known at runtime (e.g. a config), use a `static` that is initialized once:
+/// ```
+/// use std::sync::OnceLock;
+/// use prae::Wrapper;
+///
+/// const MAX_LEN: usize = 16;
+/// static BANNED: OnceLock<Vec<String>> = OnceLock::new();
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Username: String;
+///     ensure |u: &String| {
+///         u.len() <= MAX_LEN && !BANNED.get().is_some_and(|banned| banned.contains(u))
+///     };
+/// }
+///
+/// BANNED.set(vec!["root".to_owned()]).unwrap();
+/// assert!(Username::new("user").is_ok());
+/// assert!(Username::new("root").is_err());
+/// ```
+///
 /// ## Type signature
 ///
 /// This is the only required argument of the macro. It specifies the visibiliy
@@ -183,6 +301,119 @@ pub use crate::core::*;
 /// [`derive_more`](https://docs.rs/derive_more/latest/derive_more/)
 /// for more derive macros.
 ///
+/// The inner type isn't limited to scalars: tuples and small structs work too,
+/// and the closures can destructure them:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Range: (i64, i64);
+///     ensure |(lo, hi)| lo <= hi;
+/// }
+///
+/// assert!(Range::new((1i64, 5i64)).is_ok());
+/// let err = Range::new((5i64, 1i64)).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "failed to construct type Range from value (5, 1): value is invalid",
+/// );
+/// ```
+/// Note that the constructors accept `impl Into<Inner>`, so the integer
+/// literals inside of the tuple need type suffixes. Also, in the module of the
+/// wrapper, `.0` refers to the inner value itself, so use
+/// [`Wrapper::get`] to access the fields of the inner tuple (e.g.
+/// `range.get().0`).
+///
+/// The wrapper can also have type parameters, optionally with bounds:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub NonEmptyVec<T: Clone>: Vec<T>;
+///     ensure |v| !v.is_empty();
+/// }
+///
+/// assert!(NonEmptyVec::new(vec![1, 2]).is_ok());
+/// assert!(NonEmptyVec::<u8>::new(vec![]).is_err());
+/// ```
+/// The inner type can't be a bare type parameter, since the implementations of
+/// [`TryFrom`] and [`From`] for such wrapper would conflict with the blanket
+/// implementations of the standard library:
+/// ```compile_fail
+/// prae::define! {
+///     pub Positive<T: Clone>: T;
+///     ensure |_v| true;
+/// }
+/// ```
+/// The inner field is private by default, regardless of the visibility of the
+/// struct. You can widen its visibility to a restricted one (`pub(crate)`,
+/// `pub(super)` or `pub(in <path>)`), the same way you would for a tuple struct
+/// field:
+/// ```
+/// mod user {
+///     prae::define! {
+///         #[derive(Debug)]
+///         pub Username: pub(crate) String;
+///         ensure |u| !u.is_empty();
+///     }
+/// }
+///
+/// use prae::Wrapper;
+///
+/// let username = user::Username::new("user").unwrap();
+/// assert_eq!(username.0, "user");
+/// ```
+/// A plain `pub` isn't accepted, since it would let any caller write the
+/// inner field directly and bypass the validation:
+/// ```compile_fail
+/// prae::define! {
+///     pub Username: pub String;
+///     ensure |u| !u.is_empty();
+/// }
+/// ```
+/// Keep in mind that Rust privacy is module-based: a private field is still
+/// accessible from the module that defines the wrapper, so only a separate
+/// module guarantees that nobody bypasses the validation through `.0`:
+/// ```compile_fail
+/// mod user {
+///     prae::define! {
+///         #[derive(Debug)]
+///         pub Username: String;
+///     }
+/// }
+///
+/// use prae::Wrapper;
+///
+/// let username = user::Username::new("user").unwrap();
+/// let _ = username.0;
+/// ```
+/// The same applies to [`extend!`](crate::extend).
+///
+/// More complex bounds can be specified in the `where` clause after the inner
+/// type. It's added to the struct and to all of the generated `impl`s, so
+/// unmet bounds are reported at the usage of the wrapper instead of deep
+/// inside of the expanded code:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub SharedVec<T>: Vec<T> where T: Clone + Send, Vec<T>: PartialEq;
+///     ensure |v| !v.is_empty();
+/// }
+///
+/// assert!(SharedVec::new(vec![1, 2]).is_ok());
+/// ```
+/// Bounds of the type parameters and of the `where` clause must be paths
+/// without generic arguments (e.g. `Clone` or `serde::Serialize`) separated by
+/// `+`. The wrappers without type parameters accept the `where` clause too, see
+/// [Plugins](#plugins). Generic wrappers support only the `adjust`, `ensure`,
+/// `validate` and `validate_transition` closures, the
+/// [`validated_serde` flag](#validated_serde-flag) and the
+/// [plugins](#plugins), because other features expect a non-generic type.
+///
 /// # `adjust` closure
 ///
 /// This argument specifies a closure that will be executed on every
@@ -200,10 +431,116 @@ pub use crate::core::*;
 /// let mut text = Text::new("   hello world!   ").unwrap();
 /// assert_eq!(text.get(), "hello world!");
 ///
-/// text.set("   new value\n\n\n").unwrap();
+/// text.set("   new value\n\n\n");
 /// assert_eq!(text.get(), "new value");
 /// ```
 ///
+/// The closure above allocates a new string on every call, even if the value
+/// is already trimmed. The [`adjust`](crate::adjust) module contains helpers
+/// that modify the value in place instead, e.g. `adjust prae::adjust::trim;`.
+///
+/// The argument can be repeated to split the normalization into several
+/// readable steps. The closures are executed in the order of their
+/// declaration:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Tag: String;
+///     adjust prae::adjust::trim;
+///     adjust |t| t.make_ascii_lowercase();
+///     adjust |t| *t = t.split_whitespace().collect::<Vec<_>>().join("-");
+/// }
+///
+/// assert_eq!(Tag::new("  Hello   World ").unwrap().get(), "hello-world");
+/// ```
+///
+/// If the adjustment itself can fail (e.g. the value can't be normalized), specify
+/// the error type in parentheses right after the `adjust` keyword. In this case,
+/// the closure returns `Result<(), Error>`, and the error short-circuits the
+/// processing of the value:
+/// ```
+/// use prae::Wrapper;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub enum PhoneError {
+///     Unparseable,
+///     TooShort,
+/// }
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Phone: String;
+///     adjust(PhoneError) |p: &mut String| {
+///         let digits: String = p.chars().filter(|c| !matches!(c, ' ' | '-')).collect();
+///         if !digits.chars().all(|c| c.is_ascii_digit()) {
+///             return Err(PhoneError::Unparseable);
+///         }
+///         *p = digits;
+///         Ok(())
+///     };
+///     validate(PhoneError) |p: &String| {
+///         if p.len() < 7 {
+///             Err(PhoneError::TooShort)
+///         } else {
+///             Ok(())
+///         }
+///     };
+/// }
+///
+/// assert_eq!(Phone::new("555-12-34").unwrap().get(), "5551234");
+/// assert_eq!(Phone::new("555-12-3x").unwrap_err().original, PhoneError::Unparseable);
+/// assert_eq!(Phone::new("555").unwrap_err().original, PhoneError::TooShort);
+/// ```
+/// The [`Wrapper::Error`] type must implement `From` for the error type of the
+/// closure, so it's usually the same type as the one used in the [`validate`
+/// closure](#validate-closure) (or an enum that composes both). Without the
+/// other validation closures, it's the error type of the closure itself.
+///
+/// The fallible closure can be mixed with the infallible ones, and all of them
+/// are still executed in the order of their declaration. Only one fallible
+/// closure is supported.
+///
+/// **Note**:
+/// - the fallible `adjust` closure is executed by
+///   [`Wrapper::PROCESS`], but not by [`Wrapper::ADJUST`], since the latter can't
+///   fail. When it's mixed with the infallible `adjust` closures, they are
+///   folded into it to keep their order, so `ADJUST` doesn't execute them
+///   either;
+/// - it can't be used together with the [`repair` closure](#repair-closure):
+/// ```compile_fail
+/// prae::define! {
+///     pub Port: u16;
+///     adjust(&'static str) |p: &mut u16| if *p == 0 { Err("port is zero") } else { Ok(()) };
+///     ensure |p: &u16| *p >= 1024;
+///     repair |p: &u16| Some((*p).max(1024));
+/// }
+/// ```
+///
+/// # `canonicalize` closure
+///
+/// This argument specifies a closure that brings the value into its canonical
+/// form, e.g. sorts and deduplicates a vector that must be stored as a set.
+/// Just like the [`adjust` closure](#adjust-closure), it's executed on every
+/// construction and mutation of the wrapper (right after the `adjust`
+/// closure), but it makes the intention clear:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub CanonicalSet: Vec<u32>;
+///     canonicalize |set: &mut Vec<u32>| {
+///         set.sort_unstable();
+///         set.dedup();
+///     };
+/// }
+///
+/// let set = CanonicalSet::new(vec![3, 1, 2, 1]).unwrap();
+/// assert_eq!(set.get(), &[1, 2, 3]);
+/// ```
+///
 /// # `ensure` closure
 ///
 /// This argument specifies a closure that will be executed on every
@@ -224,9 +561,12 @@ pub use crate::core::*;
 /// As you can see, the closure receives a shared reference to the inner value
 /// and returns `true` if the value is valid, and `false` if it's not.
 ///
+/// Common checks are available in the [`rules`](crate::rules) module, e.g.
+/// `ensure prae::rules::len_between::<3, 20>;`.
+///
 /// This closure is easy to use, but it has a downside: you can't customize your
 /// error type. The [`Wrapper::Error`] type will always
-/// be a `&'static str` with a generic error message:
+/// be a `&'static str`, which by default contains a generic error message:
 /// ```
 /// # use prae::Wrapper;
 /// # prae::define! {
@@ -241,15 +581,266 @@ pub use crate::core::*;
 ///     "failed to construct type Text from value \"\": value is invalid",
 /// )
 /// ```
+/// If all you need is a different message, you can specify it in parentheses
+/// right after the `ensure` keyword:
+/// ```
+/// # use prae::Wrapper;
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Text: String;
+///     ensure("text is empty") |text: &String| !text.is_empty();
+/// }
+///
+/// let err = Text::new("").unwrap_err();
+/// assert_eq!(err.original, "text is empty");
+/// ```
+/// If the message should depend on the value, use the `ensure_msg` closure
+/// instead. It returns `Result<(), String>`, and the [`Wrapper::Error`] type
+/// will be a `String`:
+/// ```
+/// # use prae::Wrapper;
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Slug: String;
+///     ensure_msg |slug: &String| {
+///         if slug.chars().all(|c| c.is_ascii_lowercase() || c == '-') {
+///             Ok(())
+///         } else {
+///             Err(format!("'{}' is not a valid slug", slug))
+///         }
+///     };
+/// }
+///
+/// let err = Slug::new("Not a slug").unwrap_err();
+/// assert_eq!(err.original, "'Not a slug' is not a valid slug");
+/// ```
 /// If you want more control, use [`validate` closure](#validate-closure)
 /// closure described below.
 ///
 /// **Note**:
 /// - this closure can be used together with the [`adjust`
 ///   closure](#adjust-closure) and will be executed after it;
-/// - this closure can't be used together with the [`validate`
+/// - this closure can't be used together with the `ensure_msg` closure or the
+///   [`validate` closure](#validate-closure).
+///
+/// # `forbid` closure
+///
+/// This closure is the inverse of the [`ensure` closure](#ensure-closure): the
+/// value is invalid if the closure returns `true`. Some invariants read more
+/// naturally this way:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Username: String;
+///     forbid("username must not contain spaces") |u| u.contains(' ');
+/// }
+///
+/// assert!(Username::new("user").is_ok());
+/// let err = Username::new("user name").unwrap_err();
+/// assert_eq!(err.original, "username must not contain spaces");
+/// ```
+/// The message is optional, just like with the `ensure` closure. The `forbid`
+/// closure takes the place of the `ensure` closure, so they can't be used
+/// together.
+///
+/// # `ensure_detailed` closure
+///
+/// The error of the `ensure` closure is a bare `&'static str`, so the rejected
+/// value is only available in the wrapper-errors. If you propagate just the
+/// original error (e.g. with [`MapOriginalError`]), use the `ensure_detailed`
+/// closure instead. It works just like the `ensure` closure, but the
+/// [`Wrapper::Error`] type will be an [`EnsureError`] that also contains a
+/// [`Debug`](core::fmt::Debug) snapshot of the value:
+/// ```
+/// use prae::{MapOriginalError, Wrapper};
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Username: String;
+///     ensure_detailed("username is too short") |u| u.len() >= 3;
+/// }
+///
+/// let err = Username::new("jo").map_original().unwrap_err();
+/// assert_eq!(err.message, "username is too short");
+/// assert_eq!(err.value, "\"jo\"");
+/// assert_eq!(err.to_string(), "username is too short (got \"jo\")");
+/// ```
+/// The message is optional, just like with the `ensure` closure. The snapshot
+/// respects the [`redact_value` flag](#redact_value-flag). The
+/// `ensure_detailed` closure takes the place of the `validate` closure, so
+/// they can't be used together.
+///
+/// # `ensure const` closure
+///
+/// The `ensure` closure can be marked with the `const` keyword to generate an
+/// additional `const fn new_const` constructor. It allows to define constants
+/// that are guaranteed to be valid at compile time:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Port: u16;
+///     ensure const |port: &u16| *port >= 1024;
+/// }
+///
+/// const PORT: Port = Port::new_const(8080);
+/// assert_eq!(*PORT.get(), 8080);
+/// assert!(Port::new(80u16).is_err());
+/// ```
+/// If the value doesn't pass the check, `new_const` panics, which is a compile
+/// error in the const context:
+/// ```compile_fail
+/// # use prae::Wrapper;
+/// # prae::define! {
+/// #     #[derive(Debug)]
+/// #     pub Port: u16;
+/// #     ensure const |port: &u16| *port >= 1024;
+/// # }
+/// const PORT: Port = Port::new_const(80);
+/// # let _ = PORT;
+/// ```
+/// Since closures can't be called in the const context, the body of the
+/// closure is inlined into `new_const`, so it must be a const expression.
+/// Otherwise, the closure works just like the regular `ensure` closure.
+///
+/// **Note**:
+/// - this closure can't be used together with the [`adjust`
+///   closure](#adjust-closure) and the [`canonicalize`
+///   closure](#canonicalize-closure), since `new_const` can't run them;
+/// - this closure doesn't support custom error messages.
+///
+/// # `ensure_enum` checks
+///
+/// This argument is useful when the value must pass several checks, and you
+/// want to know which one has failed. It accepts the name of the error type and
+/// a list of checks, each consisting of a variant name and a closure similar to
+/// the [`ensure` closure](#ensure-closure). The macro generates the error enum
+/// with the same visibility as the wrapper, and the checks are executed in the
+/// specified order:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Username: String;
+///     ensure_enum(
+///         /// An error that occurs when the username is invalid.
+///         UsernameError
+///     ) {
+///         Empty => |u| !u.is_empty(),
+///         TooLong => |u| u.len() <= 16,
+///     };
+/// }
+///
+/// let err = Username::new("").unwrap_err();
+/// assert_eq!(err.original, UsernameError::Empty);
+/// let err = Username::new("a".repeat(17)).unwrap_err();
+/// assert_eq!(err.original, UsernameError::TooLong);
+/// assert_eq!(err.original.to_string(), "value doesn't pass the `TooLong` check");
+/// ```
+/// The attributes before the name of the error and before the names of the
+/// variants (e.g. doc comments) are attached to the generated enum and it's
+/// variants. It implements `Debug`, `Clone`, `Copy`,
+/// `PartialEq`, `Eq`, `Display` and [`Error`](std::error::Error).
+///
+/// **Note**:
+/// - this argument can be used together with the [`adjust`
+///   closure](#adjust-closure) and will be executed after it;
+/// - this argument can't be used together with the other validation arguments
+///   (e.g. the [`ensure` closure](#ensure-closure) or the [`validate`
+///   closure](#validate-closure)).
+///
+/// # `ensure_range` bounds
+///
+/// This argument is a shorthand for the [`ensure` closure](#ensure-closure)
+/// that checks that the value is within the inclusive range between the two
+/// specified bounds. For example:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Percent: u8;
+///     ensure_range(0, 100);
+/// }
+///
+/// assert!(Percent::new(42).is_ok());
+/// let err = Percent::new(101).unwrap_err();
+/// assert_eq!(err.original, "value is out of range");
+/// ```
+/// The [`Wrapper::Error`] type will be a `&'static str`, just like with the
+/// [`ensure` closure](#ensure-closure).
+///
+/// Since the check is a simple comparison, the macro also generates a `const
+/// fn in_range` for the wrapper. It can be used in `const` contexts, e.g. for
+/// static assertions:
+/// ```
+/// # prae::define! {
+/// #     pub Percent: u8;
+/// #     ensure_range(0, 100);
+/// # }
+/// const _: () = assert!(Percent::in_range(100));
+/// ```
+///
+/// **Note**:
+/// - this argument can be used together with the [`adjust`
+///   closure](#adjust-closure) and will be executed after it;
+/// - this argument can't be used together with the [`ensure`
+///   closure](#ensure-closure) or the [`validate`
 ///   closure](#validate-closure).
 ///
+/// # `ensure_matches_any` and `ensure_matches_none` patterns
+///
+/// These arguments (available under the `regex` feature) check the value
+/// against a list of regular expressions. The value passes
+/// `ensure_matches_any` if it matches at least one of the patterns, and it
+/// passes `ensure_matches_none` if it matches none of them. For example:
+/// ```
+/// # #[cfg(feature = "regex")] {
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Url: String;
+///     ensure_matches_any(["^https://", "^http://"]);
+///     ensure_matches_none(["localhost", r"127\.0\.0\.1"]);
+/// }
+///
+/// assert!(Url::new("https://example.com").is_ok());
+/// let err = Url::new("ftp://example.com").unwrap_err();
+/// assert_eq!(err.original, "value doesn't match any of the allowed patterns");
+/// let err = Url::new("http://localhost").unwrap_err();
+/// assert_eq!(err.original, "value matches a forbidden pattern");
+/// # }
+/// ```
+/// The patterns of each argument are compiled into a single
+/// [`RegexSet`](https://docs.rs/regex/latest/regex/struct.RegexSet.html) once,
+/// on the first validation. The inner type must implement `AsRef<str>`, and the
+/// [`Wrapper::Error`] type will be a `&'static str`, just like with the
+/// [`ensure` closure](#ensure-closure).
+///
+/// **Note**:
+/// - the patterns are not checked at compile time, so the first validation
+///   will panic if one of them is invalid;
+/// - these arguments can be used together with each other and with the
+///   [`adjust` closure](#adjust-closure), and will be executed after it;
+/// - these arguments can't be used together with the [`ensure`
+///   closure](#ensure-closure), the [`ensure_range` bounds](#ensure_range-bounds),
+///   the [`validate` closure](#validate-closure) or the [`validate_async`
+///   closure](#validate_async-closure):
+/// ```compile_fail
+/// # #[cfg(not(feature = "regex"))]
+/// # compile_error!("requires the `regex` feature");
+/// prae::define! {
+///     pub Url: String;
+///     ensure_matches_any(["^https://"]);
+///     validate_async(&'static str) async |url: &String| Ok(());
+/// }
+/// ```
+///
 /// # `validate` closure
 /// This closure is similar to the [`ensure` closure](#ensure-closure), but uses
 /// custom error specified by the user:
@@ -292,30 +883,409 @@ pub use crate::core::*;
 /// ```
 /// As you can see, the closure receives a shared reference to the inner value
 /// and returns `Ok(())` if the value is valid, and `Err(...)` if it’s not.
+/// Since the return type of the closure is known, the `?` operator
+/// converts other errors into the specified one with [`From`], which plays
+/// well with the error enums (e.g. the ones created with `thiserror`):
+/// ```
+/// use prae::Wrapper;
 ///
-/// **Note**:
-/// - this closure can be used together with the [`adjust`
-///   closure](#adjust-closure) and will be executed after it;
-/// - this closure can't be used together with the [`ensure`
-///   closure](#ensure-closure).
-///
-/// # Plugins
+/// #[derive(Debug)]
+/// pub enum PortError {
+///     Parse(std::num::ParseIntError),
+///     Reserved,
+/// }
 ///
-/// Sometimes attribute macros just dont't cut it. In this case, you have two
-/// options:
-/// - add manual `impl` to your type;
-/// - use plugins.
+/// impl From<std::num::ParseIntError> for PortError {
+///     fn from(err: std::num::ParseIntError) -> Self {
+///         Self::Parse(err)
+///     }
+/// }
 ///
-/// In the context of this macro, plugin is just a macro that takes your type as
-/// an input and does something with it.
+/// prae::define! {
+///     pub PortString: String;
+///     validate(PortError) |port| {
+///         if port.parse::<u16>()? < 1024 {
+///             return Err(PortError::Reserved);
+///         }
+///         Ok(())
+///     };
+/// }
 ///
-/// For example, suppose we want our type to implement
-/// [`serde::Serialize`](::serde::Serialize) and
-/// [`serde::Deserialize`](::serde::Deserialize). We *could* use attribute
-/// macros:
+/// assert!(PortString::new("8080").is_ok());
+/// assert!(PortString::new("http").is_err());
+/// assert!(PortString::new("80").is_err());
 /// ```
-/// use serde::{Serialize, Deserialize};
-///
+/// The error type can't borrow from the value, because [`Wrapper::Error`]
+/// has no lifetime and the value is moved into the wrapper-error after the
+/// validation. To describe the offending part of the input, copy it into the
+/// error or store its position, and slice the value of the wrapper-error
+/// later:
+/// ```
+/// use prae::Wrapper;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct InvalidChar {
+///     pub position: usize,
+///     pub ch: char,
+/// }
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Ident: String;
+///     validate(InvalidChar) |ident: &String| {
+///         match ident.char_indices().find(|(_, c)| !c.is_ascii_alphanumeric()) {
+///             Some((position, ch)) => Err(InvalidChar { position, ch }),
+///             None => Ok(()),
+///         }
+///     };
+/// }
+///
+/// let err = Ident::new("foo-bar").unwrap_err();
+/// assert_eq!(err.original, InvalidChar { position: 3, ch: '-' });
+/// assert_eq!(&err.value[err.original.position..], "-bar");
+/// ```
+///
+/// **Note**:
+/// - this closure can be used together with the [`adjust`
+///   closure](#adjust-closure) and will be executed after it;
+/// - this closure can't be used together with the [`ensure`
+///   closure](#ensure-closure), which is reported with a compile error:
+/// ```compile_fail
+/// prae::define! {
+///     pub Text: String;
+///     ensure |text: &String| !text.is_empty();
+///     validate(&'static str) |_| Ok(());
+/// }
+/// ```
+///
+/// # `repair` closure
+///
+/// By default, a value that doesn't pass the validation is rejected. This
+/// closure allows you to repair such value instead: it receives a shared
+/// reference to the invalid value and returns `Some(...)` with the repaired
+/// value, or `None` if the value can't be repaired. For example:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Volume: i32;
+///     ensure_range(0, 100);
+///     repair |v| Some((*v).clamp(0, 100));
+/// }
+///
+/// assert_eq!(Volume::new(42).unwrap().get(), &42);
+/// assert_eq!(Volume::new(-10).unwrap().get(), &0);
+/// assert_eq!(Volume::new(150).unwrap().get(), &100);
+/// ```
+/// Unlike the clamping in the [`adjust` closure](#adjust-closure), this
+/// closure only runs when the value is invalid, which makes it clear that
+/// the input was out of bounds. The repaired value is adjusted and validated
+/// again, but only once, so a buggy closure can't cause an infinite loop: if
+/// the repaired value is still invalid, the error is returned.
+///
+/// **Note**:
+/// - this closure requires one of the validation arguments (e.g. the
+///   [`ensure` closure](#ensure-closure) or the [`validate`
+///   closure](#validate-closure));
+/// - the closure only runs during the construction and mutation, it doesn't
+///   affect [`Wrapper::VALIDATE`].
+///
+/// # `validate_async` closure
+/// This closure is similar to the [`validate` closure](#validate-closure), but
+/// returns a future. It's useful for validations that are inherently
+/// asynchronous, like checking the uniqueness of a username in the database.
+///
+/// When this closure is specified, the macro generates an additional `async fn
+/// new_async` constructor for the wrapper. It runs [`Wrapper::PROCESS`] first,
+/// and then awaits the `validate_async` closure:
+/// ```
+/// # fn block_on<F: std::future::Future>(f: F) -> F::Output {
+/// #     let mut f = std::pin::pin!(f);
+/// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+/// #     loop {
+/// #         if let std::task::Poll::Ready(v) = f.as_mut().poll(&mut cx) {
+/// #             return v;
+/// #         }
+/// #     }
+/// # }
+/// # block_on(async {
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Username: String;
+///     adjust |un| *un = un.trim().to_owned();
+///     validate(&'static str) |un: &String| {
+///         if un.is_empty() {
+///             Err("username is empty")
+///         } else {
+///             Ok(())
+///         }
+///     };
+///     validate_async(&'static str) async |un: &String| {
+///         // Imagine a database query here.
+///         if un == "admin" {
+///             Err("username is taken")
+///         } else {
+///             Ok(())
+///         }
+///     };
+/// }
+///
+/// let un = Username::new_async(" user ").await.unwrap();
+/// assert_eq!(un.get(), "user");
+///
+/// let err = Username::new_async("  ").await.unwrap_err();
+/// assert_eq!(err.original, "username is empty");
+///
+/// let err = Username::new_async("admin").await.unwrap_err();
+/// assert_eq!(err.original, "username is taken");
+/// # });
+/// ```
+/// **Warning**: only `new_async` runs this closure. The synchronous methods of
+/// [`Wrapper`] (e.g. [`Wrapper::new`], [`Wrapper::try_set`] or
+/// [`Wrapper::mutate`]) and the plugins built on top of them (e.g.
+/// [`impl_serde`](crate::impl_serde)) can't await it, so they **skip the
+/// asynchronous validation**, and `Username::new("admin")` from the example
+/// above succeeds. The trait requires these methods, so they can't be left
+/// out. Treat the asynchronous check as a one-off check at the boundary
+/// (e.g. on registration), not as an invariant of the type, and use
+/// `new_async` wherever it's important.
+///
+/// The error type of the closure must be the same as the [`Wrapper::Error`]
+/// type, so it's usually combined with the [`validate`
+/// closure](#validate-closure). If no validation closure is specified, the
+/// [`Wrapper::Error`] type will be the error type of this closure. The
+/// [`ensure` closure](#ensure-closure), the [`ensure_range`
+/// bounds](#ensure_range-bounds) and the [`ensure_matches_any` and
+/// `ensure_matches_none`
+/// patterns](#ensure_matches_any-and-ensure_matches_none-patterns) define their
+/// own error type, so they can't be used together with it:
+/// ```compile_fail
+/// prae::define! {
+///     pub Username: String;
+///     ensure |un: &String| !un.is_empty();
+///     validate_async(&'static str) async |un: &String| Ok(());
+/// }
+/// ```
+///
+/// # `validate_transition` closure
+/// Some invariants are about transitions rather than states. This closure is
+/// executed on every mutation of the wrapper and receives both the old and the
+/// new inner values:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Counter: u64;
+///     validate_transition(&'static str) |old, new| {
+///         if new < old {
+///             Err("counter can't decrease")
+///         } else {
+///             Ok(())
+///         }
+///     };
+/// }
+///
+/// let mut counter = Counter::new(1u64).unwrap();
+/// counter.set(2u64);
+/// let err = counter.try_set(0u64).unwrap_err();
+/// assert_eq!(err.original, "counter can't decrease");
+/// assert_eq!(*counter.get(), 2);
+/// ```
+/// It's executed after [`Wrapper::PROCESS`] for [`Wrapper::try_set`],
+/// [`Wrapper::mutate`] and [`Wrapper::checkout`]. The construction of the
+/// wrapper has no old value, so it only runs [`Wrapper::PROCESS`]. The same
+/// goes for [`Wrapper::get_mut`], since it doesn't keep the old value.
+///
+/// Just like with the [`validate_async` closure](#validate_async-closure),
+/// the error type of the closure must be the same as the [`Wrapper::Error`]
+/// type.
+///
+/// Wrappers created with [`extend!`](crate::extend) inherit this closure from
+/// the wrapper they extend.
+///
+/// # `skippable` closure
+/// This closure generates an `is_skippable` method for the wrapper. It's meant
+/// to be used with the `#[serde(skip_serializing_if = "...")]` attribute to
+/// omit the wrapper from the output when its value is valid, but not worth
+/// serializing:
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use prae::Wrapper;
+/// use serde::Serialize;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Bio: String;
+///     adjust |bio| *bio = bio.trim().to_owned();
+///     skippable |bio| bio.is_empty();
+///     plugins: [
+///         prae::impl_serde,
+///     ];
+/// }
+///
+/// #[derive(Serialize)]
+/// pub struct User {
+///     #[serde(skip_serializing_if = "Bio::is_skippable")]
+///     bio: Bio,
+/// }
+///
+/// let user = User { bio: Bio::new("   ").unwrap() };
+/// assert_eq!(serde_json::to_string(&user).unwrap(), "{}");
+/// # }
+/// ```
+///
+/// # `validated_serde` flag
+///
+/// This flag (available under the `serde` feature) implements
+/// [`serde::Serialize`](::serde::Serialize) and
+/// [`serde::Deserialize`](::serde::Deserialize) for the wrapper, so that the
+/// deserialized values pass through the closures of the wrapper. It's
+/// equivalent to the [`impl_serde`] plugin described below:
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Username: String;
+///     ensure |un| !un.is_empty();
+///     validated_serde;
+/// }
+///
+/// assert!(serde_json::from_str::<Username>("\"\"").is_err());
+/// # }
+/// ```
+/// Deriving `Deserialize` for the wrapper is a common mistake, since the
+/// derived implementation skips the validation. To catch it, the macro looks
+/// for the `Deserialize` identifier inside of the `#[derive(...)]` attributes
+/// of the type signature (so both `Deserialize` and `serde::Deserialize` are
+/// detected) and emits a deprecation warning if it's found, so it's rejected
+/// by `#![deny(deprecated)]`:
+/// ```compile_fail
+/// #![deny(deprecated)]
+/// # #[cfg(not(feature = "serde"))]
+/// # compile_error!("requires the `serde` feature");
+///
+/// prae::define! {
+///     #[derive(Debug, serde::Deserialize)]
+///     pub Username: String;
+///     ensure |un| !un.is_empty();
+/// }
+/// ```
+/// If you really need the derived implementation, silence the warning with
+/// `#[allow(deprecated)]` on the enclosing module.
+///
+/// # `redact_value` flag
+///
+/// By default, the errors of the wrapper print the value that caused them
+/// using its [`Debug`](core::fmt::Debug) implementation. It's unacceptable for
+/// secrets, since the errors usually end up in the logs. This flag replaces
+/// the value with `<redacted>` in the [`Display`](core::fmt::Display) output
+/// of the errors:
+/// ```
+/// # use prae::Wrapper;
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Password: String;
+///     ensure |p: &String| p.len() >= 8;
+///     redact_value;
+/// }
+///
+/// let err = Password::new("hunter2").unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "failed to construct type Password from value <redacted>: value is invalid",
+/// );
+/// ```
+/// Note that the value is still accessible through the fields of the errors,
+/// and their [`Debug`](core::fmt::Debug) output is not affected. Wrappers
+/// created with [`extend!`] inherit this flag from the extended wrapper.
+///
+/// # `truncate_value` flag
+///
+/// The [`Debug`](core::fmt::Debug) output of a huge value (e.g. a network
+/// payload) bloats the errors of the wrapper. This flag limits the value to
+/// the provided number of characters, followed by `…` if it's longer, in the
+/// [`Display`](core::fmt::Display) output of the errors. Pass `hex` before the
+/// limit to print at most the provided number of bytes of the inner value as
+/// a lowercase hex string instead, if it implements
+/// [`AsRef<[u8]>`](core::convert::AsRef):
+/// ```
+/// # use prae::Wrapper;
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Frame: Vec<u8>;
+///     ensure |f: &Vec<u8>| f.first() == Some(&0x7e);
+///     truncate_value(hex, 4);
+/// }
+///
+/// let err = Frame::new(vec![0xde, 0xad, 0xbe, 0xef, 0x00, 0x01]).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "failed to construct type Frame from value deadbeef…: value is invalid",
+/// );
+/// ```
+/// The [`redact_value` flag](#redact_value-flag) takes precedence over this
+/// one. Just like with it, the fields of the errors and their
+/// [`Debug`](core::fmt::Debug) output are not affected. Wrappers created with
+/// [`extend!`] inherit this flag from the extended wrapper, unless they widen
+/// the inner type. To truncate the [`Display`](core::fmt::Display) output of
+/// the wrapper itself, use the
+/// [`impl_display_truncated`](impl_display_truncated) plugin.
+///
+/// # `forward` methods
+///
+/// This argument lists methods of the inner value that should be available on
+/// the wrapper as well. For every method, the macro generates an inherent
+/// method with the same name and visibility of the wrapper that calls it on
+/// the inner value:
+/// ```
+/// # use prae::Wrapper;
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Email: String;
+///     ensure |e: &String| e.contains('@');
+///     forward(
+///         as_str -> &str,
+///         to_lowercase -> String,
+///         len -> usize,
+///         is_empty -> bool,
+///     );
+/// }
+///
+/// let email = Email::new("User@Example.com").unwrap();
+/// assert_eq!(email.as_str(), "User@Example.com");
+/// assert_eq!(email.to_lowercase(), "user@example.com");
+/// assert_eq!(email.len(), 16);
+/// assert!(!email.is_empty());
+/// ```
+/// `macro_rules!` can't look up the signatures of the methods, so the return
+/// type of every method must be specified after `->`. Only methods that take
+/// `&self` and no other arguments can be forwarded. The forwarded methods get
+/// only a shared reference to the inner value, so they can't break its
+/// invariants, unless the inner type has interior mutability (e.g. a
+/// [`Cell`](core::cell::Cell)) and the method uses it. Don't forward such
+/// methods.
+///
+/// # Plugins
+///
+/// Sometimes attribute macros just dont't cut it. In this case, you have two
+/// options:
+/// - add manual `impl` to your type;
+/// - use plugins.
+///
+/// In the context of this macro, plugin is just a macro that takes your type as
+/// an input and does something with it.
+///
+/// For example, suppose we want our type to implement
+/// [`serde::Serialize`](::serde::Serialize) and
+/// [`serde::Deserialize`](::serde::Deserialize). We *could* use attribute
+/// macros:
+/// ```
+/// use serde::{Serialize, Deserialize};
+///
 /// prae::define! {
 ///     #[derive(Serialize, Deserialize)]
 ///     pub Username: String;
@@ -350,6 +1320,7 @@ pub use crate::core::*;
 /// [`serde::Serialize`](::serde::Serialize) and
 /// [`serde::Deserialize`](::serde::Deserialize) the right way:
 /// ```
+/// # #[cfg(feature = "serde")] {
 /// use prae::Wrapper;
 /// use serde::{Serialize, Deserialize};
 ///
@@ -370,95 +1341,970 @@ pub use crate::core::*;
 /// // But this won't
 /// let err = serde_json::from_str::<Username>("\"   \"").unwrap_err();
 /// assert_eq!(err.to_string(), "value is invalid");
+/// # }
+/// ```
+/// Most plugins require the inner type to implement some traits, which are
+/// listed in their documentation (e.g. [`impl_serde`] requires
+/// [`serde::Serialize`](::serde::Serialize) and
+/// [`serde::Deserialize`](::serde::Deserialize)). If the inner type doesn't
+/// implement them, the error points into the expansion of the plugin. To get
+/// it at the type signature instead, state the requirements in the `where`
+/// clause after the inner type. It's added to the struct and to the `impl`s of
+/// the plugins:
+/// ```
+/// use prae::Wrapper;
+/// use std::fmt::Display;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Port: u16 where u16: Display;
+///     ensure |p| *p != 0;
+///     plugins: [
+///         prae::impl_display,
+///     ];
+/// }
+///
+/// assert_eq!(Port::new(8080u16).unwrap().to_string(), "8080");
 /// ```
+/// The same restrictions apply to the bounds of the `where` clause as to the
+/// ones of the [generic wrappers](#type-signature).
+///
+/// Some plugins accept arguments, which are passed in parentheses after the
+/// path of the plugin. For example, [`impl_hash_eq_by`] accepts a closure that
+/// projects the inner value to the key used for hashing and comparison.
+///
+/// The plugins work with the [generic wrappers](#type-signature) too:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     pub Tags<T: std::fmt::Debug>: Vec<T>;
+///     ensure |t| !t.is_empty();
+///     plugins: [
+///         prae::impl_debug,
+///         prae::impl_index,
+///     ];
+/// }
+///
+/// let tags = Tags::new(vec!["a", "b"]).unwrap();
+/// assert_eq!(tags[1], "b");
+/// assert_eq!(format!("{:?}", tags), "Tags([\"a\", \"b\"])");
+/// ```
+///
 /// You can implement your own plugins and use them for your types - it's easy.
+/// The plugin is invoked as `plugin!(Wrapper)`, or as `plugin!(Wrapper,
+/// <arguments>)` if the arguments were provided:
+/// ```
+/// use prae::Wrapper;
+///
+/// macro_rules! impl_display_with_prefix {
+///     ($wrapper:ident, prefix = $prefix:literal) => {
+///         impl std::fmt::Display for $wrapper {
+///             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///                 write!(f, "{}{}", $prefix, self.get())
+///             }
+///         }
+///     };
+/// }
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Username: String;
+///     plugins: [
+///         impl_display_with_prefix(prefix = "user:"),
+///     ];
+/// }
+///
+/// let un = Username::new("bob").unwrap();
+/// assert_eq!(un.to_string(), "user:bob");
+/// ```
+/// For the generic wrappers and the wrappers with the `where` clause, the
+/// plugin is invoked as `plugin!(@generic Wrapper [<parameters with bounds>]
+/// [<parameters>] [<where clause>])`, followed by the arguments the same way.
+/// Each list is empty if there is nothing to put there, and the `impl`s of the
+/// plugin are expected to look like this:
+/// ```
+/// use prae::Wrapper;
+///
+/// macro_rules! impl_len {
+///     ($wrapper:ident) => {
+///         impl_len!(@generic $wrapper [] [] []);
+///     };
+///     (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*]) => {
+///         impl<$($impl)*> $wrapper<$($ty)*>
+///         where
+///             $($where)*
+///         {
+///             pub fn len(&self) -> usize {
+///                 self.get().len()
+///             }
+///         }
+///     };
+/// }
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Batch<T: Clone>: Vec<T>;
+///     plugins: [impl_len];
+/// }
+///
+/// assert_eq!(Batch::new(vec![1, 2]).unwrap().len(), 2);
+/// ```
 #[macro_export]
 macro_rules! define {
+    // Public inner field:
+    // - Rejected, because the inner field can only be restricted: `pub` would
+    //   let anybody write it without the validation. Other types after a bare
+    //   `pub` don't match any arm.
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident $(<$($param:ident $(: $($bound:ident)::+ $(+ $($bounds:ident)::+)*)?),+ $(,)?>)?: pub $inner:ident
+        $($rest:tt)*
+    } => {
+        ::core::compile_error!("the inner field of the wrapper can't be `pub`, since anybody could \
+            bypass the validation through it, use a restricted visibility like `pub(crate)` instead");
+    };
+    // Invalid signature:
+    // - A bare type parameter as the inner type (the only single-identifier
+    //   type that a generic wrapper can use), for which the conversion traits
+    //   can't be implemented. The arm with the visibility of the inner field
+    //   just strips it, since an optional visibility before `$inner:ident` is
+    //   ambiguous for `macro_rules!`.
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident<$($param:ident $(: $($bound:ident)::+ $(+ $($bounds:ident)::+)*)?),+ $(,)?>:
+            $inner:ident
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        $($rest:tt)*
+    } => {
+        ::core::compile_error!(
+            "the inner type of a generic wrapper can't be a bare type parameter, since the \
+            `From` and `TryFrom` implementations of the wrapper would conflict with the blanket \
+            ones, use a non-generic wrapper for each inner type instead"
+        );
+    };
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident<$($param:ident $(: $($bound:ident)::+ $(+ $($bounds:ident)::+)*)?),+ $(,)?>:
+            pub($($field_vis:tt)+) $inner:ident
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        $($rest:tt)*
+    } => {
+        $crate::define! {
+            $vis $wrapper<$($param $(: $($bound)::+ $(+ $($bounds)::+)*)?),+>: $inner;
+        }
+    };
+    // Fallible `adjust` closure:
+    // - Rewrites `adjust(<error>) <closure>` into the internal form, because
+    //   the parenthesized error type followed by the closure is parsed as a
+    //   bitwise OR expression by `macro_rules!`.
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident: $(pub($($field_vis:tt)+))? $inner:ty
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        adjust($adjust_err:ty) $try_adjust:expr;
+        $($rest:tt)*
+    } => {
+        $crate::define! {
+            $(#[$($meta)*])*
+            $vis $wrapper: $(pub($($field_vis)+))? $inner
+                $(where $($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?;
+            adjust @error($adjust_err) $try_adjust;
+            $($rest)*
+        }
+    };
+    // `adjust` closures after the fallible one:
+    // - Folds every following `adjust` closure into the fallible one, so that
+    //   the closures still run in the order of their declaration.
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident: $(pub($($field_vis:tt)+))? $inner:ty
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        adjust @error($adjust_err:ty) $try_adjust:expr;
+        adjust $adjust:expr;
+        $($rest:tt)*
+    } => {
+        $crate::define! {
+            $(#[$($meta)*])*
+            $vis $wrapper: $(pub($($field_vis)+))? $inner
+                $(where $($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?;
+            adjust @error($adjust_err) |value: &mut Self::Inner| -> Result<(), $adjust_err> {
+                let try_adjust: fn(&mut Self::Inner) -> Result<(), $adjust_err> = $try_adjust;
+                try_adjust(value)?;
+                let adjust: fn(&mut Self::Inner) = $adjust;
+                adjust(value);
+                Ok(())
+            };
+            $($rest)*
+        }
+    };
+    // `adjust` closure before the fallible one:
+    // - Folds the preceding `adjust` closure into the fallible one for the
+    //   same reason. It can't be matched with a repetition, because both the
+    //   closure and the parenthesized error type can start with `(`, so the
+    //   preceding closures are folded into one by the next arm first.
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident: $(pub($($field_vis:tt)+))? $inner:ty
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        adjust $adjust:expr;
+        adjust($adjust_err:ty) $try_adjust:expr;
+        $($rest:tt)*
+    } => {
+        $crate::define! {
+            $(#[$($meta)*])*
+            $vis $wrapper: $(pub($($field_vis)+))? $inner
+                $(where $($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?;
+            adjust($adjust_err) |value: &mut Self::Inner| -> Result<(), $adjust_err> {
+                let adjust: fn(&mut Self::Inner) = $adjust;
+                adjust(value);
+                let try_adjust: fn(&mut Self::Inner) -> Result<(), $adjust_err> = $try_adjust;
+                try_adjust(value)
+            };
+            $($rest)*
+        }
+    };
+    // Several `adjust` closures before the fallible one:
+    // - Folds the first two closures into one, until the arm above matches.
+    //   It also matches three or more infallible closures, which doesn't
+    //   change their order either.
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident: $(pub($($field_vis:tt)+))? $inner:ty
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        adjust $first:expr;
+        adjust $second:expr;
+        adjust $($rest:tt)*
+    } => {
+        $crate::define! {
+            $(#[$($meta)*])*
+            $vis $wrapper: $(pub($($field_vis)+))? $inner
+                $(where $($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?;
+            adjust |value: &mut Self::Inner| {
+                let first: fn(&mut Self::Inner) = $first;
+                first(value);
+                let second: fn(&mut Self::Inner) = $second;
+                second(value);
+            };
+            adjust $($rest)*
+        }
+    };
+    // Const `ensure` closure:
+    // - Rewrites `ensure const <closure>` into the regular form and generates
+    //   the `new_const` constructor with the body of the closure inlined,
+    //   because closures can't be called in the const context. It must come
+    //   first, because `const` can't be parsed as the beginning of the closure
+    //   expression by the other arms.
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident: $(pub($($field_vis:tt)+))? $inner:ty
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        ensure const |$value:ident $(: $value_ty:ty)?| $check:expr;
+        $($rest:tt)*
+    } => {
+        $crate::define! {
+            $(#[$($meta)*])*
+            $vis $wrapper: $(pub($($field_vis)+))? $inner
+                $(where $($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?;
+            ensure |$value $(: $value_ty)?| $check;
+            $($rest)*
+        }
+        impl $wrapper {
+            #[doc = concat!(
+                "Construct a new `", stringify!($wrapper), "` in the const context, ",
+                "panicking if the value doesn't pass its `ensure const` closure."
+            )]
+            $vis const fn new_const(value: $inner) -> Self {
+                {
+                    let $value: &$inner = &value;
+                    if !$check {
+                        panic!(concat!(
+                            "value doesn't pass the const check of type ",
+                            stringify!($wrapper),
+                        ));
+                    }
+                }
+                Self(value)
+            }
+        }
+    };
+    // Custom `ensure` message:
+    // - Rewrites `ensure("...") <closure>` into the internal form, because the
+    //   parenthesized message is indistinguishable from the beginning of an
+    //   expression for `macro_rules!`.
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident: $(pub($($field_vis:tt)+))? $inner:ty
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        $(adjust $adjust:expr;)*
+        $(adjust @error($adjust_err:ty) $try_adjust:expr;)?
+        $(canonicalize $canonicalize:expr;)?
+        ensure($msg:literal) $ensure:expr;
+        $($rest:tt)*
+    } => {
+        $crate::define! {
+            $(#[$($meta)*])*
+            $vis $wrapper: $(pub($($field_vis)+))? $inner
+                $(where $($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?;
+            $(adjust $adjust;)*
+            $(adjust @error($adjust_err) $try_adjust;)?
+            $(canonicalize $canonicalize;)?
+            ensure @message($msg) $ensure;
+            $($rest)*
+        }
+    };
+    // Custom `ensure` message of a generic wrapper:
+    // - Same as above, but with type parameters in the type signature.
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident<$($param:ident $(: $($bound:ident)::+ $(+ $($bounds:ident)::+)*)?),+ $(,)?>: $(pub($($field_vis:tt)+))? $inner:ty
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        $(adjust $adjust:expr;)*
+        ensure($msg:literal) $ensure:expr;
+        $($rest:tt)*
+    } => {
+        $crate::define! {
+            $(#[$($meta)*])*
+            $vis $wrapper<$($param $(: $($bound)::+ $(+ $($bounds)::+)*)?),+>: $(pub($($field_vis)+))? $inner
+                $(where $($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?;
+            $(adjust $adjust;)*
+            ensure @message($msg) $ensure;
+            $($rest)*
+        }
+    };
+    // `forbid` closure with a custom message:
+    // - Rewrites `forbid("...") <closure>` into an `ensure` closure with the
+    //   inverted result. It must go before the arm without the message for the
+    //   same reason as above.
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident: $(pub($($field_vis:tt)+))? $inner:ty
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        $(adjust $adjust:expr;)*
+        $(adjust @error($adjust_err:ty) $try_adjust:expr;)?
+        $(canonicalize $canonicalize:expr;)?
+        forbid($msg:literal) $forbid:expr;
+        $($rest:tt)*
+    } => {
+        $crate::define! {
+            $(#[$($meta)*])*
+            $vis $wrapper: $(pub($($field_vis)+))? $inner
+                $(where $($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?;
+            $(adjust $adjust;)*
+            $(adjust @error($adjust_err) $try_adjust;)?
+            $(canonicalize $canonicalize;)?
+            ensure @message($msg) |_v: &Self::Inner| {
+                let forbid: fn(&Self::Inner) -> bool = $forbid;
+                !forbid(_v)
+            };
+            $($rest)*
+        }
+    };
+    // `forbid` closure:
+    // - Rewrites `forbid <closure>` into an `ensure` closure with the inverted
+    //   result.
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident: $(pub($($field_vis:tt)+))? $inner:ty
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        $(adjust $adjust:expr;)*
+        $(adjust @error($adjust_err:ty) $try_adjust:expr;)?
+        $(canonicalize $canonicalize:expr;)?
+        forbid $forbid:expr;
+        $($rest:tt)*
+    } => {
+        $crate::define! {
+            $(#[$($meta)*])*
+            $vis $wrapper: $(pub($($field_vis)+))? $inner
+                $(where $($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?;
+            $(adjust $adjust;)*
+            $(adjust @error($adjust_err) $try_adjust;)?
+            $(canonicalize $canonicalize;)?
+            ensure |_v: &Self::Inner| {
+                let forbid: fn(&Self::Inner) -> bool = $forbid;
+                !forbid(_v)
+            };
+            $($rest)*
+        }
+    };
+    // `forbid` closure of a generic wrapper with a custom message:
+    // - Same as above, but with type parameters in the type signature.
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident<$($param:ident $(: $($bound:ident)::+ $(+ $($bounds:ident)::+)*)?),+ $(,)?>: $(pub($($field_vis:tt)+))? $inner:ty
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        $(adjust $adjust:expr;)*
+        forbid($msg:literal) $forbid:expr;
+        $($rest:tt)*
+    } => {
+        $crate::define! {
+            $(#[$($meta)*])*
+            $vis $wrapper<$($param $(: $($bound)::+ $(+ $($bounds)::+)*)?),+>: $(pub($($field_vis)+))? $inner
+                $(where $($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?;
+            $(adjust $adjust;)*
+            ensure @message($msg) |_v: &Self::Inner| {
+                let forbid: fn(&Self::Inner) -> bool = $forbid;
+                !forbid(_v)
+            };
+            $($rest)*
+        }
+    };
+    // `forbid` closure of a generic wrapper:
+    // - Same as above, but with type parameters in the type signature.
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident<$($param:ident $(: $($bound:ident)::+ $(+ $($bounds:ident)::+)*)?),+ $(,)?>: $(pub($($field_vis:tt)+))? $inner:ty
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        $(adjust $adjust:expr;)*
+        forbid $forbid:expr;
+        $($rest:tt)*
+    } => {
+        $crate::define! {
+            $(#[$($meta)*])*
+            $vis $wrapper<$($param $(: $($bound)::+ $(+ $($bounds)::+)*)?),+>: $(pub($($field_vis)+))? $inner
+                $(where $($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?;
+            $(adjust $adjust;)*
+            ensure |_v: &Self::Inner| {
+                let forbid: fn(&Self::Inner) -> bool = $forbid;
+                !forbid(_v)
+            };
+            $($rest)*
+        }
+    };
+    // `ensure_detailed` closure with a custom message:
+    // - Rewrites `ensure_detailed("...") <closure>` into a `validate` closure
+    //   that returns `EnsureError`. It must go before the arm without the
+    //   message for the same reason as the `forbid` arms.
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident: $(pub($($field_vis:tt)+))? $inner:ty
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        $(adjust $adjust:expr;)*
+        $(adjust @error($adjust_err:ty) $try_adjust:expr;)?
+        $(canonicalize $canonicalize:expr;)?
+        ensure_detailed($msg:literal) $ensure:expr;
+        $($rest:tt)*
+    } => {
+        $crate::define! {
+            $(#[$($meta)*])*
+            $vis $wrapper: $(pub($($field_vis)+))? $inner
+                $(where $($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?;
+            $(adjust $adjust;)*
+            $(adjust @error($adjust_err) $try_adjust;)?
+            $(canonicalize $canonicalize;)?
+            validate($crate::EnsureError) |_v: &Self::Inner| {
+                let ensure: fn(&Self::Inner) -> bool = $ensure;
+                if ensure(_v) {
+                    Ok(())
+                } else {
+                    Err($crate::EnsureError::new::<Self>($crate::__ensure_message!($msg), _v))
+                }
+            };
+            $($rest)*
+        }
+    };
+    // `ensure_detailed` closure:
+    // - Rewrites `ensure_detailed <closure>` into a `validate` closure that
+    //   returns `EnsureError`.
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident: $(pub($($field_vis:tt)+))? $inner:ty
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        $(adjust $adjust:expr;)*
+        $(adjust @error($adjust_err:ty) $try_adjust:expr;)?
+        $(canonicalize $canonicalize:expr;)?
+        ensure_detailed $ensure:expr;
+        $($rest:tt)*
+    } => {
+        $crate::define! {
+            $(#[$($meta)*])*
+            $vis $wrapper: $(pub($($field_vis)+))? $inner
+                $(where $($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?;
+            $(adjust $adjust;)*
+            $(adjust @error($adjust_err) $try_adjust;)?
+            $(canonicalize $canonicalize;)?
+            validate($crate::EnsureError) |_v: &Self::Inner| {
+                let ensure: fn(&Self::Inner) -> bool = $ensure;
+                if ensure(_v) {
+                    Ok(())
+                } else {
+                    Err($crate::EnsureError::new::<Self>($crate::__ensure_message!(), _v))
+                }
+            };
+            $($rest)*
+        }
+    };
+    // `ensure_detailed` closure of a generic wrapper with a custom message:
+    // - Same as above, but with type parameters in the type signature.
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident<$($param:ident $(: $($bound:ident)::+ $(+ $($bounds:ident)::+)*)?),+ $(,)?>: $(pub($($field_vis:tt)+))? $inner:ty
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        $(adjust $adjust:expr;)*
+        ensure_detailed($msg:literal) $ensure:expr;
+        $($rest:tt)*
+    } => {
+        $crate::define! {
+            $(#[$($meta)*])*
+            $vis $wrapper<$($param $(: $($bound)::+ $(+ $($bounds)::+)*)?),+>: $(pub($($field_vis)+))? $inner
+                $(where $($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?;
+            $(adjust $adjust;)*
+            validate($crate::EnsureError) |_v: &Self::Inner| {
+                let ensure: fn(&Self::Inner) -> bool = $ensure;
+                if ensure(_v) {
+                    Ok(())
+                } else {
+                    Err($crate::EnsureError::new::<Self>($crate::__ensure_message!($msg), _v))
+                }
+            };
+            $($rest)*
+        }
+    };
+    // `ensure_detailed` closure of a generic wrapper:
+    // - Same as above, but with type parameters in the type signature.
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident<$($param:ident $(: $($bound:ident)::+ $(+ $($bounds:ident)::+)*)?),+ $(,)?>: $(pub($($field_vis:tt)+))? $inner:ty
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        $(adjust $adjust:expr;)*
+        ensure_detailed $ensure:expr;
+        $($rest:tt)*
+    } => {
+        $crate::define! {
+            $(#[$($meta)*])*
+            $vis $wrapper<$($param $(: $($bound)::+ $(+ $($bounds)::+)*)?),+>: $(pub($($field_vis)+))? $inner
+                $(where $($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?;
+            $(adjust $adjust;)*
+            validate($crate::EnsureError) |_v: &Self::Inner| {
+                let ensure: fn(&Self::Inner) -> bool = $ensure;
+                if ensure(_v) {
+                    Ok(())
+                } else {
+                    Err($crate::EnsureError::new::<Self>($crate::__ensure_message!(), _v))
+                }
+            };
+            $($rest)*
+        }
+    };
+    // Generic wrapper:
+    // - Optional attribute macro;
+    // - Required type signature with type parameters;
+    // - Optional closures that don't require a non-generic type.
+    // - Optional plugins, which receive the type parameters and the `where`
+    //   clause.
+    {
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident<$($param:ident $(: $($bound:ident)::+ $(+ $($bounds:ident)::+)*)?),+ $(,)?>: $(pub($($field_vis:tt)+))? $inner:ty
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        $(adjust $adjust:expr;)*
+        $(ensure $(@message($msg:literal))? $ensure:expr;)?
+        $(validate($err:ty) $validate:expr;)?
+        $(validate_transition($transition_err:ty) $validate_transition:expr;)?
+        // The semicolon is captured to have a variable for the transcription.
+        $(validated_serde $validated_serde:tt)?
+        $(plugins: [$($(:: $(@$plugin_root:tt)?)? $($plugin:ident)::+ $(($($arg:tt)*))?),+ $(,)?];)?
+    } => {
+        $(#[$($meta)*])*
+        $vis struct $wrapper<$($param $(: $($bound)::+ $(+ $($bounds)::+)*)?),+>($(pub($($field_vis)+))? $inner)
+            $(where $($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?;
+        $crate::__check_attributes!($([$($meta)*])*);
+        impl<$($param $(: $($bound)::+ $(+ $($bounds)::+)*)?),+> $crate::Wrapper for $wrapper<$($param),+>
+        $(where $($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?
+        {
+            const NAME: &'static str = stringify!($wrapper);
+            type Inner = $inner;
+            const ADJUST: fn(&mut Self::Inner) = |_v| {
+                $({
+                    let adjust: fn(&mut Self::Inner) = $adjust;
+                    adjust(_v);
+                })*
+            };
+            $crate::define!(
+                $(ensure $(@message($msg))? $ensure;)?
+                $(validate($err) $validate;)?
+                $(@error($transition_err);)?
+            );
+            $(
+                const VALIDATE_TRANSITION: fn(&Self::Inner, &Self::Inner) -> Result<(), Self::Error> =
+                    $validate_transition;
+            )?
+            $crate::__impl_wrapper_methods!();
+        }
+        $crate::__impl_external_traits!(
+            $wrapper<$($param $(: $($bound)::+ $(+ $($bounds)::+)*)?),+>, $inner
+            $(, where [$($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+])?
+        );
+        $crate::__validated_serde!(
+            [$($validated_serde)?] $wrapper
+            [$($param $(: $($bound)::+ $(+ $($bounds)::+)*)?),+] [$($param),+]
+            [$($($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?]
+        );
+        $crate::__impl_plugins!(
+            $wrapper
+            [$($param $(: $($bound)::+ $(+ $($bounds)::+)*)?),+] [$($param),+]
+            [$($($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?];
+            [$($($(:: $(@$plugin_root)?)? $($plugin)::+ $(($($arg)*))?),+)?]
+        );
+    };
     // Required part:
     // - Optional attribute macro;
     // - Required type signature;
     // - Optional closures.
     // - Optional plugins.
     {
-        $(#[$meta:meta])*
-        $vis:vis $wrapper:ident: $inner:ty;
-        $(adjust $adjust:expr;)?
-        $(ensure $ensure:expr;)?
+        $(#[$($meta:tt)*])*
+        $vis:vis $wrapper:ident: $(pub($($field_vis:tt)+))? $inner:ty
+            $(where $($where_ty:ty: $($where_bound:ident)::+ $(+ $($where_bounds:ident)::+)*),+ $(,)?)?;
+        $(adjust $adjust:expr;)*
+        $(adjust @error($adjust_err:ty) $try_adjust:expr;)?
+        $(canonicalize $canonicalize:expr;)?
+        $(ensure $(@message($msg:literal))? $ensure:expr;)?
+        $(ensure_msg $ensure_msg:expr;)?
+        $(ensure_enum($(#[$enum_meta:meta])* $enum_err:ident) {
+            $($(#[$variant_meta:meta])* $variant:ident => $check:expr),+ $(,)?
+        };)?
+        $(ensure_range($min:expr, $max:expr);)?
+        $(ensure_matches_any([$($any:literal),+ $(,)?]);)?
+        $(ensure_matches_none([$($none:literal),+ $(,)?]);)?
         $(validate($err:ty) $validate:expr;)?
-        $(plugins: [$($plugin:path),+ $(,)?];)?
+        $(repair $repair:expr;)?
+        $(validate_async($async_err:ty) $validate_async:expr;)?
+        $(validate_transition($transition_err:ty) $validate_transition:expr;)?
+        $(skippable $skippable:expr;)?
+        // The semicolon is captured to have a variable for the transcription.
+        $(validated_serde $validated_serde:tt)?
+        $(redact_value $redact_value:tt)?
+        $(truncate_value($($truncate_value:tt)+);)?
+        $(forward($($forward:ident -> $forward_ret:ty),+ $(,)?);)?
+        // The inner repetition of the leading `::` never matches, it only
+        // gives the transcriber a variable to repeat the `::` with.
+        $(plugins: [$($(:: $(@$plugin_root:tt)?)? $($plugin:ident)::+ $(($($arg:tt)*))?),+ $(,)?];)?
     } => {
-        $(#[$meta])*
-        $vis struct $wrapper($inner);
+        $(
+            $(#[$enum_meta])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            $vis enum $enum_err {
+                $($(#[$variant_meta])* $variant),+
+            }
+            impl ::core::fmt::Display for $enum_err {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let variant = match self {
+                        $(Self::$variant => stringify!($variant)),+
+                    };
+                    write!(f, "value doesn't pass the `{}` check", variant)
+                }
+            }
+            impl ::core::error::Error for $enum_err {}
+        )?
+        $(#[$($meta)*])*
+        $vis struct $wrapper($(pub($($field_vis)+))? $inner)
+            $(where $($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?;
+        $crate::__check_attributes!($([$($meta)*])*);
         impl $crate::Wrapper for $wrapper {
             const NAME: &'static str = stringify!($wrapper);
             type Inner = $inner;
+            const ADJUST: fn(&mut Self::Inner) = |_v| {
+                $({
+                    let adjust: fn(&mut Self::Inner) = $adjust;
+                    adjust(_v);
+                })*
+                $({
+                    let canonicalize: fn(&mut Self::Inner) = $canonicalize;
+                    canonicalize(_v);
+                })?
+            };
             $crate::define!(
-                $(adjust $adjust;)?
-                $(ensure $ensure;)?
+                $(ensure $(@message($msg))? $ensure;)?
+                $(ensure_msg $ensure_msg;)?
+                $(@ensure_enum($enum_err) { $($variant => $check),+ };)?
+                $(ensure_range($min, $max);)?
+                $(@matches_any($($any),+);)?
+                $(@matches_none($($none),+);)?
                 $(validate($err) $validate;)?
+                $(@async_error($async_err);)?
+                $(@error($transition_err);)?
+                $(@error($adjust_err);)?
+            );
+            $crate::__impl_process!(
+                [$(@error($adjust_err) $try_adjust)?]
+                [$(repair $repair)?]
             );
+            $(
+                const VALIDATE_TRANSITION: fn(&Self::Inner, &Self::Inner) -> Result<(), Self::Error> =
+                    $validate_transition;
+            )?
+            $(const REDACT_VALUE: bool = true $redact_value)?
+            $(
+                const FMT_VALUE: Option<
+                    fn(&Self::Inner, &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result,
+                > = Some($crate::__truncate_value!($($truncate_value)+));
+            )?
             $crate::__impl_wrapper_methods!();
         }
+        $(
+            impl $wrapper {
+                #[doc = concat!(
+                    "Construct a new `", stringify!($wrapper), "`, running its ",
+                    "`validate_async` closure after `Wrapper::PROCESS`."
+                )]
+                $vis async fn new_async(
+                    value: impl Into<$inner>,
+                ) -> Result<Self, $crate::ConstructionError<Self>> {
+                    let wrapper = <Self as $crate::Wrapper>::new(value)?;
+                    let validate_async = $validate_async;
+                    match validate_async(&wrapper.0).await {
+                        Ok(()) => Ok(wrapper),
+                        Err(original) => Err($crate::ConstructionError {
+                            original,
+                            value: wrapper.0,
+                            rejection: $crate::Rejection {
+                                phase: $crate::ProcessPhase::Validate,
+                                wrapper: <Self as $crate::Wrapper>::NAME,
+                            },
+                        }),
+                    }
+                }
+            }
+        )?
+        $(
+            impl $wrapper {
+                #[doc = concat!(
+                    "Check if the value is within the range specified by the ",
+                    "`ensure_range` of `", stringify!($wrapper), "`."
+                )]
+                $vis const fn in_range(value: $inner) -> bool {
+                    value >= $min && value <= $max
+                }
+            }
+        )?
+        $(
+            impl $wrapper {
+                #[doc = concat!(
+                    "Check if the value should be skipped during the serialization, ",
+                    "according to the `skippable` closure of `", stringify!($wrapper), "`."
+                )]
+                $vis fn is_skippable(&self) -> bool {
+                    let skippable: fn(&$inner) -> bool = $skippable;
+                    skippable(&self.0)
+                }
+            }
+        )?
+        $(
+            impl $wrapper {
+                $(
+                    #[doc = concat!("Call `", stringify!($forward), "` on the inner value.")]
+                    $vis fn $forward(&self) -> $forward_ret {
+                        self.0.$forward()
+                    }
+                )+
+            }
+        )?
         $crate::__impl_external_traits!($wrapper, $inner);
-        $($($plugin!($wrapper);)*)?
+        $crate::__validated_serde!([$($validated_serde)?] $wrapper [] [] [$($($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?]);
+        $crate::__impl_plugins!(
+            $wrapper [] [] [$($($where_ty: $($where_bound)::+ $(+ $($where_bounds)::+)*),+)?];
+            [$($($(:: $(@$plugin_root)?)? $($plugin)::+ $(($($arg)*))?),+)?]
+        );
     };
     // Optional closures 1:
-    // - Optional `adjust` closure.
+    // - No closures.
+    {} => {
+        type Error = ::core::convert::Infallible;
+        const VALIDATE: fn(&Self::Inner) -> Result<(), Self::Error> = |_| Ok(());
+    };
+    // Optional closures 2:
+    // - Required error type of `validate_async`, `validate_transition` or the
+    //   fallible `adjust`.
     {
-        $(adjust $adjust:expr;)?
+        $(@async_error($async_err:ty);)?
+        @error($err:ty);
+        $(@error($other_err:ty);)*
     } => {
-        type Error = ::core::convert::Infallible;
-        const PROCESS: fn(&mut Self::Inner) -> Result<(), Self::Error> = |mut _v| {
+        type Error = $err;
+        const VALIDATE: fn(&Self::Inner) -> Result<(), Self::Error> = |_| Ok(());
+    };
+    {
+        @async_error($err:ty);
+        $(@error($other_err:ty);)*
+    } => {
+        type Error = $err;
+        const VALIDATE: fn(&Self::Inner) -> Result<(), Self::Error> = |_| Ok(());
+    };
+    // Invalid combination:
+    // - The `validate_async` closure with the `ensure` closure or the
+    //   `ensure_range` bounds, which define the `&'static str` error type on
+    //   their own, so the error type of `validate_async` would be ignored. It's
+    //   still used as the error type, so that the only reported error is the
+    //   one below.
+    {
+        $(ensure $(@message($msg:literal))? $ensure:expr;)?
+        $(ensure_range($min:expr, $max:expr);)?
+        @async_error($async_err:ty);
+        $(@error($other_err:ty);)*
+    } => {
+        ::core::compile_error!(
+            "`validate_async` closure can't be used together with the `ensure` closure \
+            or the `ensure_range` bounds in `define!`, move the checks into the `validate` \
+            closure that returns the error type of `validate_async`"
+        );
+        type Error = $async_err;
+        const VALIDATE: fn(&Self::Inner) -> Result<(), Self::Error> = |_| Ok(());
+    };
+    // Optional closures 3:
+    // - Required `ensure` closure.
+    // - Optional error types of `validate_transition` and the fallible
+    //   `adjust`.
+    {
+        ensure $(@message($msg:literal))? $ensure:expr;
+        $(@error($other_err:ty);)*
+    } => {
+        type Error = &'static str;
+        const VALIDATE: fn(&Self::Inner) -> Result<(), Self::Error> = |_v| {
+            let ensure: fn(&Self::Inner) -> bool = $ensure;
+            if !ensure(_v) {
+                return Err($crate::__ensure_message!($($msg)?))
+            }
+            Ok(())
+        };
+    };
+    // Optional closures 4:
+    // - Required `ensure_msg` closure.
+    // - Optional error types of `validate_async`, `validate_transition` and the
+    //   fallible `adjust`.
+    {
+        ensure_msg $ensure_msg:expr;
+        $(@async_error($async_err:ty);)?
+        $(@error($other_err:ty);)*
+    } => {
+        type Error = $crate::__private::String;
+        const VALIDATE: fn(&Self::Inner) -> Result<(), Self::Error> = |_v| {
+            let ensure_msg: fn(&Self::Inner) -> Result<(), Self::Error> = $ensure_msg;
+            ensure_msg(_v)
+        };
+    };
+    // Optional closures 5:
+    // - Required `ensure_enum` checks.
+    // - Optional error types of `validate_async`, `validate_transition` and the
+    //   fallible `adjust`.
+    {
+        @ensure_enum($enum_err:ident) { $($variant:ident => $check:expr),+ };
+        $(@async_error($async_err:ty);)?
+        $(@error($other_err:ty);)*
+    } => {
+        type Error = $enum_err;
+        const VALIDATE: fn(&Self::Inner) -> Result<(), Self::Error> = |_v| {
             $({
-                let adjust: fn(&mut Self::Inner) = $adjust;
-                adjust(&mut _v);
-            })?
+                let check: fn(&Self::Inner) -> bool = $check;
+                if !check(_v) {
+                    return Err($enum_err::$variant);
+                }
+            })+
             Ok(())
         };
     };
-    // Optional closures 2:
-    // - Optional `adjust` closure.
-    // - Required `ensure` closure.
+    // Optional closures 6:
+    // - Required `ensure_range` bounds.
+    // - Optional error types of `validate_transition` and the fallible
+    //   `adjust`.
+    {
+        ensure_range($min:expr, $max:expr);
+        $(@error($other_err:ty);)*
+    } => {
+        type Error = &'static str;
+        const VALIDATE: fn(&Self::Inner) -> Result<(), Self::Error> = |_v| {
+            if *_v < $min || *_v > $max {
+                return Err("value is out of range")
+            }
+            Ok(())
+        };
+    };
+    // Optional closures 7:
+    // - Optional `ensure_matches_any` patterns.
+    // - Optional `ensure_matches_none` patterns.
+    // - Optional error types of `validate_transition` and the fallible
+    //   `adjust`.
     {
-        $(adjust $adjust:expr;)?
-        ensure $ensure:expr;
+        $(@matches_any($($any:literal),+);)?
+        $(@matches_none($($none:literal),+);)?
+        $(@error($other_err:ty);)*
     } => {
         type Error = &'static str;
-        const PROCESS: fn(&mut Self::Inner) -> Result<(), Self::Error> = |mut _v| {
+        const VALIDATE: fn(&Self::Inner) -> Result<(), Self::Error> = |_v| {
+            let _v: &str = ::core::convert::AsRef::as_ref(_v);
             $({
-                let adjust: fn(&mut Self::Inner) = $adjust;
-                adjust(&mut _v);
+                static SET: $crate::__private::OnceLock<$crate::__private::regex::RegexSet> =
+                    $crate::__private::OnceLock::new();
+                let set = SET.get_or_init(|| {
+                    $crate::__private::regex::RegexSet::new([$($any),+])
+                        .expect("invalid `ensure_matches_any` pattern")
+                });
+                if !set.is_match(_v) {
+                    return Err("value doesn't match any of the allowed patterns");
+                }
             })?
-            {
-                let ensure: fn(&Self::Inner) -> bool = $ensure;
-                if !ensure(&_v) {
-                    return Err("value is invalid")
+            $({
+                static SET: $crate::__private::OnceLock<$crate::__private::regex::RegexSet> =
+                    $crate::__private::OnceLock::new();
+                let set = SET.get_or_init(|| {
+                    $crate::__private::regex::RegexSet::new([$($none),+])
+                        .expect("invalid `ensure_matches_none` pattern")
+                });
+                if set.is_match(_v) {
+                    return Err("value matches a forbidden pattern");
                 }
-            }
+            })?
             Ok(())
         };
     };
-    // Optional closures 3:
-    // - Optional `adjust` closure.
+    // Invalid combination:
+    // - The `validate_async` closure with the `ensure_matches_any` or
+    //   `ensure_matches_none` patterns, which define the `&'static str` error
+    //   type on their own, just like the `ensure` closure.
+    {
+        $(@matches_any($($any:literal),+);)?
+        $(@matches_none($($none:literal),+);)?
+        @async_error($async_err:ty);
+        $(@error($other_err:ty);)*
+    } => {
+        ::core::compile_error!(
+            "`validate_async` closure can't be used together with the `ensure_matches_any` \
+            or `ensure_matches_none` patterns in `define!`, move the checks into the \
+            `validate` closure that returns the error type of `validate_async`"
+        );
+        type Error = $async_err;
+        const VALIDATE: fn(&Self::Inner) -> Result<(), Self::Error> = |_| Ok(());
+    };
+    // Optional closures 8:
     // - Required `validate` closure.
+    // - Optional error types of `validate_async`, `validate_transition` and the
+    //   fallible `adjust`.
     {
-        $(adjust $adjust:expr;)?
         validate($err:ty) $validate:expr;
+        $(@async_error($async_err:ty);)?
+        $(@error($other_err:ty);)*
     } => {
         type Error = $err;
-        const PROCESS: fn(&mut Self::Inner) -> Result<(), Self::Error> = |mut _v| {
-            $({
-                let adjust: fn(&mut Self::Inner) = $adjust;
-                adjust(&mut _v);
-            })?
-            {
-                let validate: fn(&Self::Inner) -> Result<(), Self::Error> = $validate;
-                if let Err(err) = validate(&_v) {
-                    return Err(err)
-                }
-            }
-            Ok(())
+        const VALIDATE: fn(&Self::Inner) -> Result<(), Self::Error> = |_v| {
+            let validate: fn(&Self::Inner) -> Result<(), Self::Error> = $validate;
+            validate(_v)
         };
+    };
+    // Invalid combination:
+    // - Both `ensure` and `validate` closures, which would define two error
+    //   types. The `validate` closure is still used, so that the only reported
+    //   error is the one below.
+    {
+        ensure $(@message($msg:literal))? $ensure:expr;
+        validate($err:ty) $validate:expr;
+        $(@async_error($async_err:ty);)?
+        $(@error($other_err:ty);)*
+    } => {
+        ::core::compile_error!(
+            "`ensure` and `validate` closures can't be used together in `define!`, \
+            move the check of the `ensure` closure into the `validate` closure"
+        );
+        type Error = $err;
+        const VALIDATE: fn(&Self::Inner) -> Result<(), Self::Error> = $validate;
     }
 }
 
@@ -505,8 +2351,101 @@ macro_rules! define {
 /// // Doesn't pass the validation of `Sentence`
 /// assert!(Sentence::new("Without punctuation").is_err());
 /// ```
+///
+/// # Widening the inner type
+///
+/// The created struct can also have a different inner type, which stores the
+/// value of that another wrapper in one of it's fields. In this case, the type
+/// signature must specify the new inner type after `as`, and the `project`
+/// argument must specify the field (name or index) that contains the value of
+/// that another wrapper. The adjustment and validation closures of that another
+/// wrapper will run on this field:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     pub Text: String;
+///     adjust |text| *text = text.trim().to_owned();
+///     ensure |text| !text.is_empty();
+/// }
+///
+/// prae::extend! {
+///     #[derive(Debug)]
+///     pub CachedText: Text as (String, usize);
+///     project 0;
+///     adjust |(text, len)| *len = text.chars().count();
+/// }
+///
+/// let text = CachedText::new(("  My text ".to_owned(), 0)).unwrap();
+/// assert_eq!(text.get(), &("My text".to_owned(), 7));
+///
+/// // Doesn't pass the validation of `Text`
+/// assert!(CachedText::new(("   ".to_owned(), 0)).is_err());
+/// ```
+///
+/// # Plugins
+///
+/// The plugins of the extended wrapper are **not** inherited, because they
+/// were applied to the extended struct, not to the created one. The same goes
+/// for the attribute macros of the type signature. To keep the API of the
+/// types consistent, list the plugins that should be applied again in the
+/// `reapply` argument. It accepts the same syntax as `plugins` and goes right
+/// before it:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Text: String;
+///     ensure |text| !text.is_empty();
+///     plugins: [prae::impl_display];
+/// }
+///
+/// prae::extend! {
+///     #[derive(Debug)]
+///     pub Sentence: Text;
+///     ensure |sentence: &String| sentence.ends_with('.');
+///     reapply: [prae::impl_display];
+///     plugins: [prae::impl_deref];
+/// }
+///
+/// let sentence = Sentence::new("My sentence.").unwrap();
+/// assert_eq!(sentence.to_string(), "My sentence.");
+/// assert_eq!(sentence.len(), 12);
+/// ```
 #[macro_export]
 macro_rules! extend {
+    // Public inner field:
+    // - Rejected for the same reason as in `define!`.
+    {
+        $(#[$meta:meta])*
+        $vis:vis $wrapper:ident: pub $inner:ident
+        $($rest:tt)*
+    } => {
+        ::core::compile_error!("the inner field of the wrapper can't be `pub`, since anybody could \
+            bypass the validation through it, use a restricted visibility like `pub(crate)` instead");
+    };
+    // Custom `ensure` message:
+    // - Rewrites `ensure("...") <closure>` into the internal form, because the
+    //   parenthesized message is indistinguishable from the beginning of an
+    //   expression for `macro_rules!`.
+    {
+        $(#[$meta:meta])*
+        $vis:vis $wrapper:ident: $(pub($($field_vis:tt)+))? $inner:ty $(as $new_inner:ty)?;
+        $(project $field:tt;)?
+        $(adjust $adjust:expr;)*
+        ensure($msg:literal) $ensure:expr;
+        $($rest:tt)*
+    } => {
+        $crate::extend! {
+            $(#[$meta])*
+            $vis $wrapper: $(pub($($field_vis)+))? $inner $(as $new_inner)?;
+            $(project $field;)?
+            $(adjust $adjust;)*
+            ensure @message($msg) $ensure;
+            $($rest)*
+        }
+    };
     // Required part:
     // - Optional attribute macro;
     // - Required type signature;
@@ -514,120 +2453,453 @@ macro_rules! extend {
     // - Optional plugins.
     {
         $(#[$meta:meta])*
-        $vis:vis $wrapper:ident: $inner:ty;
-        $(adjust $adjust:expr;)?
-        $(ensure $ensure:expr;)?
+        $vis:vis $wrapper:ident: $(pub($($field_vis:tt)+))? $inner:ty;
+        $(adjust $adjust:expr;)*
+        $(ensure $(@message($msg:literal))? $ensure:expr;)?
         $(validate($err:ty) $validate:expr;)?
-        $(plugins: [$($plugin:path),+ $(,)?];)?
+        $(reapply: [$($(:: $(@$reapply_root:tt)?)? $($reapply:ident)::+ $(($($reapply_arg:tt)*))?),+ $(,)?];)?
+        $(plugins: [$($(:: $(@$plugin_root:tt)?)? $($plugin:ident)::+ $(($($arg:tt)*))?),+ $(,)?];)?
 
     } => {
         $(#[$meta])*
-        $vis struct $wrapper(<$inner as $crate::Wrapper>::Inner);
+        $vis struct $wrapper($(pub($($field_vis)+))? <$inner as $crate::Wrapper>::Inner);
         impl $crate::Wrapper for $wrapper {
             const NAME: &'static str = stringify!($wrapper);
             type Inner = <$inner as $crate::Wrapper>::Inner;
+            const ADJUST: fn(&mut Self::Inner) = |_v| {
+                <$inner as $crate::Wrapper>::ADJUST(_v);
+                $({
+                    let adjust: fn(&mut Self::Inner) = $adjust;
+                    adjust(_v);
+                })*
+            };
             $crate::extend!(
                 $inner;
-                $(adjust $adjust;)?
-                $(ensure $ensure;)?
+                $(adjust $adjust;)*
+                $(ensure $(@message($msg))? $ensure;)?
                 $(validate($err) $validate;)?
             );
+            const VALIDATE_TRANSITION: fn(&Self::Inner, &Self::Inner) -> Result<(), Self::Error> =
+                |old, new| {
+                    <$inner as $crate::Wrapper>::VALIDATE_TRANSITION(old, new)?;
+                    Ok(())
+                };
+            const REDACT_VALUE: bool = <$inner as $crate::Wrapper>::REDACT_VALUE;
+            const FMT_VALUE: Option<
+                fn(&Self::Inner, &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result,
+            > = <$inner as $crate::Wrapper>::FMT_VALUE;
             $crate::__impl_wrapper_methods!();
         }
         $crate::__impl_external_traits!($wrapper, <$inner as $crate::Wrapper>::Inner);
-        $($($plugin!($wrapper);)*)?
+        $($($(:: $(@$reapply_root)?)? $($reapply)::+!($wrapper $(, $($reapply_arg)*)?);)*)?
+        $($($(:: $(@$plugin_root)?)? $($plugin)::+!($wrapper $(, $($arg)*)?);)*)?
+    };
+    // Widened inner type:
+    // - Optional attribute macro;
+    // - Required type signature with the new inner type;
+    // - Required projection to the field with the extended wrapper's value;
+    // - Optional closures.
+    // - Optional plugins.
+    {
+        $(#[$meta:meta])*
+        $vis:vis $wrapper:ident: $(pub($($field_vis:tt)+))? $parent:ty as $inner:ty;
+        project $field:tt;
+        $(adjust $adjust:expr;)*
+        $(ensure $(@message($msg:literal))? $ensure:expr;)?
+        $(validate($err:ty) $validate:expr;)?
+        $(reapply: [$($(:: $(@$reapply_root:tt)?)? $($reapply:ident)::+ $(($($reapply_arg:tt)*))?),+ $(,)?];)?
+        $(plugins: [$($(:: $(@$plugin_root:tt)?)? $($plugin:ident)::+ $(($($arg:tt)*))?),+ $(,)?];)?
+    } => {
+        $(#[$meta])*
+        $vis struct $wrapper($(pub($($field_vis)+))? $inner);
+        impl $crate::Wrapper for $wrapper {
+            const NAME: &'static str = stringify!($wrapper);
+            type Inner = $inner;
+            const ADJUST: fn(&mut Self::Inner) = |_v| {
+                <$parent as $crate::Wrapper>::ADJUST(&mut _v.$field);
+                $({
+                    let adjust: fn(&mut Self::Inner) = $adjust;
+                    adjust(_v);
+                })*
+            };
+            $crate::extend!(
+                $parent, $field;
+                $(adjust $adjust;)*
+                $(ensure $(@message($msg))? $ensure;)?
+                $(validate($err) $validate;)?
+            );
+            const VALIDATE_TRANSITION: fn(&Self::Inner, &Self::Inner) -> Result<(), Self::Error> =
+                |old, new| {
+                    <$parent as $crate::Wrapper>::VALIDATE_TRANSITION(&old.$field, &new.$field)?;
+                    Ok(())
+                };
+            const REDACT_VALUE: bool = <$parent as $crate::Wrapper>::REDACT_VALUE;
+            $crate::__impl_wrapper_methods!();
+        }
+        $crate::__impl_external_traits!($wrapper, $inner);
+        $($($(:: $(@$reapply_root)?)? $($reapply)::+!($wrapper $(, $($reapply_arg)*)?);)*)?
+        $($($(:: $(@$plugin_root)?)? $($plugin)::+!($wrapper $(, $($arg)*)?);)*)?
     };
     // Optional closures 1:
-    // - Optional `adjust` closure.
+    // - Optional `adjust` closures.
     {
-        $inner:ty;
-        $(adjust $adjust:expr;)?
+        $inner:ty $(, $field:tt)?;
+        $(adjust $adjust:expr;)*
     } => {
         type Error = &'static str;
-        const PROCESS: fn(&mut Self::Inner) -> Result<(), Self::Error> = |mut _v| {
-            <$inner as $crate::Wrapper>::PROCESS(&mut _v)?;
+        const VALIDATE: fn(&Self::Inner) -> Result<(), Self::Error> = |_v| {
+            <$inner as $crate::Wrapper>::VALIDATE(&_v $(.$field)?)?;
+            Ok(())
+        };
+        const PROCESS_TRACED: fn(
+            &mut Self::Inner,
+        ) -> Result<(), (Self::Error, $crate::Rejection)> = |value| {
+            let extended = <$inner as $crate::Wrapper>::PROCESS_TRACED(&mut (*value) $(.$field)?);
+            if let Err((err, rejection)) = extended {
+                let original = ::core::convert::From::from(err);
+                let rejection = $crate::Rejection {
+                    phase: $crate::ProcessPhase::Extend,
+                    wrapper: rejection.wrapper,
+                };
+                return Err((original, rejection));
+            }
             $({
                 let adjust: fn(&mut Self::Inner) = $adjust;
-                adjust(&mut _v);
-            })?
+                adjust(value);
+            })*
             Ok(())
         };
+        const PROCESS: fn(&mut Self::Inner) -> Result<(), Self::Error> = |value| {
+            Self::PROCESS_TRACED(value).map_err(|(original, _)| original)
+        };
     };
     // Optional closures 2:
-    // - Optional `adjust` closure.
+    // - Optional `adjust` closures.
     // - Required `ensure` closure.
     {
-        $inner:ty;
-        $(adjust $adjust:expr;)?
-        ensure $ensure:expr;
+        $inner:ty $(, $field:tt)?;
+        $(adjust $adjust:expr;)*
+        ensure $(@message($msg:literal))? $ensure:expr;
     } => {
         type Error = &'static str;
-        const PROCESS: fn(&mut Self::Inner) -> Result<(), Self::Error> = |mut _v| {
-            <$inner as $crate::Wrapper>::PROCESS(&mut _v)?;
+        const VALIDATE: fn(&Self::Inner) -> Result<(), Self::Error> = |_v| {
+            <$inner as $crate::Wrapper>::VALIDATE(&_v $(.$field)?)?;
+            {
+                let ensure: fn(&Self::Inner) -> bool = $ensure;
+                if !ensure(_v) {
+                    return Err($crate::__ensure_message!($($msg)?))
+                }
+            }
+            Ok(())
+        };
+        const PROCESS_TRACED: fn(
+            &mut Self::Inner,
+        ) -> Result<(), (Self::Error, $crate::Rejection)> = |value| {
+            let extended = <$inner as $crate::Wrapper>::PROCESS_TRACED(&mut (*value) $(.$field)?);
+            if let Err((err, rejection)) = extended {
+                let original = ::core::convert::From::from(err);
+                let rejection = $crate::Rejection {
+                    phase: $crate::ProcessPhase::Extend,
+                    wrapper: rejection.wrapper,
+                };
+                return Err((original, rejection));
+            }
             $({
                 let adjust: fn(&mut Self::Inner) = $adjust;
-                adjust(&mut _v);
-            })?
+                adjust(value);
+            })*
             {
                 let ensure: fn(&Self::Inner) -> bool = $ensure;
-                if !ensure(&_v) {
-                    return Err("value is invalid")
+                if !ensure(value) {
+                    let original = $crate::__ensure_message!($($msg)?);
+                    let rejection = $crate::Rejection {
+                        phase: $crate::ProcessPhase::Validate,
+                        wrapper: Self::NAME,
+                    };
+                    return Err((original, rejection));
                 }
             }
             Ok(())
         };
+        const PROCESS: fn(&mut Self::Inner) -> Result<(), Self::Error> = |value| {
+            Self::PROCESS_TRACED(value).map_err(|(original, _)| original)
+        };
     };
     // Optional closures 3:
-    // - Optional `adjust` closure.
+    // - Optional `adjust` closures.
     // - Required `validate` closure.
     {
-        $inner:ty;
-        $(adjust $adjust:expr;)?
+        $inner:ty $(, $field:tt)?;
+        $(adjust $adjust:expr;)*
         validate($err:ty) $validate:expr;
     } => {
         type Error = $err;
-        const PROCESS: fn(&mut Self::Inner) -> Result<(), Self::Error> = |mut _v| {
-            <$inner as $crate::Wrapper>::PROCESS(&mut _v)?;
+        const VALIDATE: fn(&Self::Inner) -> Result<(), Self::Error> = |_v| {
+            <$inner as $crate::Wrapper>::VALIDATE(&_v $(.$field)?)?;
+            {
+                let validate: fn(&Self::Inner) -> Result<(), Self::Error> = $validate;
+                validate(_v)?;
+            }
+            Ok(())
+        };
+        const PROCESS_TRACED: fn(
+            &mut Self::Inner,
+        ) -> Result<(), (Self::Error, $crate::Rejection)> = |value| {
+            let extended = <$inner as $crate::Wrapper>::PROCESS_TRACED(&mut (*value) $(.$field)?);
+            if let Err((err, rejection)) = extended {
+                let original = ::core::convert::From::from(err);
+                let rejection = $crate::Rejection {
+                    phase: $crate::ProcessPhase::Extend,
+                    wrapper: rejection.wrapper,
+                };
+                return Err((original, rejection));
+            }
             $({
                 let adjust: fn(&mut Self::Inner) = $adjust;
-                adjust(&mut _v);
-            })?
+                adjust(value);
+            })*
             {
                 let validate: fn(&Self::Inner) -> Result<(), Self::Error> = $validate;
-                if let Err(err) = validate(&_v) {
-                    return Err(err)
-                }
+                validate(value).map_err(|original| {
+                    let rejection = $crate::Rejection {
+                        phase: $crate::ProcessPhase::Validate,
+                        wrapper: Self::NAME,
+                    };
+                    (original, rejection)
+                })
             }
-            Ok(())
+        };
+        const PROCESS: fn(&mut Self::Inner) -> Result<(), Self::Error> = |value| {
+            Self::PROCESS_TRACED(value).map_err(|(original, _)| original)
         };
     }
 }
 
+/// Create an `ensure` closure that checks the value against a regular
+/// expression (available under the `regex` feature):
+/// ```
+/// # #[cfg(feature = "regex")] {
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Slug: String;
+///     ensure prae::matches!(r"^[a-z0-9]+(-[a-z0-9]+)*$");
+/// }
+///
+/// assert!(Slug::new("my-first-post").is_ok());
+/// assert!(Slug::new("My first post").is_err());
+/// # }
+/// ```
+/// The pattern is compiled into a
+/// [`Regex`](https://docs.rs/regex/latest/regex/struct.Regex.html) once, on
+/// the first validation, and shared by all the following ones. The inner type
+/// must implement `AsRef<str>`.
+///
+/// **Note**: the pattern must be a string literal, which is checked at compile
+/// time, but the pattern itself is not, so the first validation will panic if
+/// it is invalid. To check the value against several patterns, use the
+/// [`ensure_matches_any` and `ensure_matches_none`
+/// patterns](crate::define#ensure_matches_any-and-ensure_matches_none-patterns).
+#[cfg(feature = "regex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+#[macro_export]
+macro_rules! matches {
+    ($pattern:literal $(,)?) => {
+        |value: &_| -> bool {
+            const PATTERN: &str = $pattern;
+            static REGEX: $crate::__private::OnceLock<$crate::__private::regex::Regex> =
+                $crate::__private::OnceLock::new();
+            let regex = REGEX.get_or_init(|| {
+                $crate::__private::regex::Regex::new(PATTERN).expect("invalid `matches!` pattern")
+            });
+            regex.is_match(::core::convert::AsRef::<str>::as_ref(value))
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check_attributes {
+    () => {};
+    ([derive($($derive:tt)*)] $($rest:tt)*) => {
+        $crate::__check_derive!($($derive)*);
+        $crate::__check_attributes!($($rest)*);
+    };
+    ([$($other:tt)*] $($rest:tt)*) => {
+        $crate::__check_attributes!($($rest)*);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check_derive {
+    () => {};
+    (Deserialize $($rest:tt)*) => {
+        const _: () = $crate::__private::derive_deserialize();
+    };
+    ($other:tt $($rest:tt)*) => {
+        $crate::__check_derive!($($rest)*);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ensure_message {
+    () => {
+        "value is invalid"
+    };
+    ($msg:literal) => {
+        $msg
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __validated_serde {
+    ([] $wrapper:ident $impl:tt $ty:tt $where:tt) => {};
+    ([;] $wrapper:ident $impl:tt $ty:tt $where:tt) => {
+        $crate::impl_serde!(@generic $wrapper $impl $ty $where);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_plugins {
+    // Non-generic wrapper without the `where` clause:
+    // - The plugins are invoked as `plugin!(Wrapper)`, so that the custom
+    //   plugins don't have to support the generic form.
+    (
+        $wrapper:ident [] [] [];
+        [$($(:: $(@$plugin_root:tt)?)? $($plugin:ident)::+ $(($($arg:tt)*))?),*]
+    ) => {
+        $($(:: $(@$plugin_root)?)? $($plugin)::+!($wrapper $(, $($arg)*)?);)*
+    };
+    // Generic wrapper or a wrapper with the `where` clause:
+    // - The plugins are invoked as
+    //   `plugin!(@generic Wrapper [<impl generics>] [<type arguments>] [<where clause>])`.
+    (
+        $wrapper:ident $impl:tt $ty:tt $where:tt;
+        [$($(:: $(@$plugin_root:tt)?)? $($plugin:ident)::+ $(($($arg:tt)*))?),*]
+    ) => {
+        $($(:: $(@$plugin_root)?)? $($plugin)::+!(@generic $wrapper $impl $ty $where $(, $($arg)*)?);)*
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __truncate_value {
+    (hex, $max:expr) => {
+        |value, f| {
+            $crate::__private::fmt_hex_truncated(
+                ::core::convert::AsRef::<[u8]>::as_ref(value),
+                $max,
+                f,
+            )
+        }
+    };
+    ($max:expr) => {
+        |value, f| $crate::__private::fmt_debug_truncated(value, $max, f)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_process {
+    // Fallible `adjust` closure.
+    ([@error($adjust_err:ty) $try_adjust:expr] []) => {
+        const PROCESS_TRACED: fn(&mut Self::Inner) -> Result<(), (Self::Error, $crate::Rejection)> =
+            |value| {
+                let try_adjust: fn(&mut Self::Inner) -> Result<(), $adjust_err> = $try_adjust;
+                let rejection = |phase| $crate::Rejection {
+                    phase,
+                    wrapper: Self::NAME,
+                };
+                if let Err(err) = try_adjust(value) {
+                    let original = ::core::convert::From::from(err);
+                    return Err((original, rejection($crate::ProcessPhase::Adjust)));
+                }
+                Self::ADJUST(value);
+                Self::VALIDATE(value)
+                    .map_err(|original| (original, rejection($crate::ProcessPhase::Validate)))
+            };
+        const PROCESS: fn(&mut Self::Inner) -> Result<(), Self::Error> =
+            |value| Self::PROCESS_TRACED(value).map_err(|(original, _)| original);
+    };
+    // `repair` closure.
+    ([] [repair $repair:expr]) => {
+        const PROCESS: fn(&mut Self::Inner) -> Result<(), Self::Error> = |_v| {
+            Self::ADJUST(_v);
+            let original = match Self::VALIDATE(_v) {
+                Ok(()) => return Ok(()),
+                Err(original) => original,
+            };
+            let repair: fn(&Self::Inner) -> Option<Self::Inner> = $repair;
+            match repair(_v) {
+                Some(repaired) => {
+                    *_v = repaired;
+                    Self::ADJUST(_v);
+                    Self::VALIDATE(_v)
+                }
+                None => Err(original),
+            }
+        };
+    };
+    // Neither, so the default `PROCESS` is used.
+    ([] []) => {};
+    // Invalid combination:
+    // - Both the fallible `adjust` closure and the `repair` closure, which
+    //   would define two `PROCESS` functions.
+    ([$($try_adjust:tt)+] [$($repair:tt)+]) => {
+        ::core::compile_error!(
+            "fallible `adjust` closure can't be used together with the `repair` closure \
+            in `define!`, make the `adjust` closure infallible or move the fallible part \
+            into the `validate` closure"
+        );
+    };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __impl_wrapper_methods {
     () => {
         fn new(value: impl Into<Self::Inner>) -> Result<Self, $crate::ConstructionError<Self>> {
             let mut value = value.into();
-            match Self::PROCESS(&mut value) {
+            match Self::PROCESS_TRACED(&mut value) {
                 Ok(()) => Ok(Self(value)),
-                Err(original) => Err($crate::ConstructionError { original, value }),
+                Err((original, rejection)) => Err($crate::ConstructionError {
+                    original,
+                    value,
+                    rejection,
+                }),
             }
         }
         fn get(&self) -> &Self::Inner {
             &self.0
         }
-        fn set(
+        fn try_set(
             &mut self,
             value: impl Into<Self::Inner>,
         ) -> Result<(), $crate::ConstructionError<Self>> {
             let mut value = value.into();
-            match Self::PROCESS(&mut value) {
+            match Self::PROCESS_TRACED(&mut value).and_then(|()| {
+                Self::VALIDATE_TRANSITION(&self.0, &value).map_err(|original| {
+                    let rejection = $crate::Rejection {
+                        phase: $crate::ProcessPhase::Validate,
+                        wrapper: Self::NAME,
+                    };
+                    (original, rejection)
+                })
+            }) {
                 Ok(()) => {
                     self.0 = value;
                     Ok(())
                 }
-                Err(original) => Err($crate::ConstructionError { original, value }),
+                Err((original, rejection)) => Err($crate::ConstructionError {
+                    original,
+                    value,
+                    rejection,
+                }),
             }
         }
         fn into_inner(self) -> Self::Inner {
@@ -640,18 +2912,23 @@ macro_rules! __impl_wrapper_methods {
         ) -> Result<(), $crate::MutationError<Self>> {
             let mut value = clone(&self.0);
             f(&mut value);
-            match Self::PROCESS(&mut value) {
+            match Self::PROCESS(&mut value)
+                .and_then(|()| Self::VALIDATE_TRANSITION(&self.0, &value))
+            {
                 Ok(()) => {
                     self.0 = value;
                     Ok(())
                 }
                 Err(original) => Err($crate::MutationError {
                     original,
-                    old_value: clone(&self.0),
+                    old_value: Some(clone(&self.0)),
                     new_value: value,
                 }),
             }
         }
+        fn __get_mut(&mut self, _: $crate::__private::Token) -> &mut Self::Inner {
+            &mut self.0
+        }
         fn new_unprocessed(value: impl Into<Self::Inner>) -> Self {
             let mut value = value.into();
             debug_assert!(Self::PROCESS(&mut value).is_ok());
@@ -681,6 +2958,42 @@ macro_rules! __impl_wrapper_methods {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __impl_external_traits {
+    ($wrapper:ident<$($param:ident $(: $($bound:ident)::+ $(+ $($bounds:ident)::+)*)?),+>, $inner:ty $(, where [$($where:tt)*])?) => {
+        impl<$($param $(: $($bound)::+ $(+ $($bounds)::+)*)?),+> ::core::convert::AsRef<$inner>
+            for $wrapper<$($param),+>
+        $(where $($where)*)?
+        {
+            fn as_ref(&self) -> &$inner {
+                &self.0
+            }
+        }
+        impl<$($param $(: $($bound)::+ $(+ $($bounds)::+)*)?),+> ::core::borrow::Borrow<$inner>
+            for $wrapper<$($param),+>
+        $(where $($where)*)?
+        {
+            fn borrow(&self) -> &$inner {
+                &self.0
+            }
+        }
+        #[allow(unknown_lints, clippy::infallible_try_from)]
+        impl<$($param $(: $($bound)::+ $(+ $($bounds)::+)*)?),+> ::core::convert::TryFrom<$inner>
+            for $wrapper<$($param),+>
+        $(where $($where)*)?
+        {
+            type Error = $crate::ConstructionError<Self>;
+            fn try_from(value: $inner) -> Result<Self, Self::Error> {
+                <Self as $crate::Wrapper>::new(value)
+            }
+        }
+        impl<$($param $(: $($bound)::+ $(+ $($bounds)::+)*)?),+> ::core::convert::From<$wrapper<$($param),+>>
+            for $inner
+        $(where $($where)*)?
+        {
+            fn from(wrapper: $wrapper<$($param),+>) -> Self {
+                wrapper.0
+            }
+        }
+    };
     ($wrapper:ident, $inner:ty) => {
         impl ::core::convert::AsRef<$inner> for $wrapper {
             fn as_ref(&self) -> &$inner {
@@ -692,6 +3005,7 @@ macro_rules! __impl_external_traits {
                 &self.0
             }
         }
+        #[allow(unknown_lints, clippy::infallible_try_from)]
         impl ::core::convert::TryFrom<$inner> for $wrapper {
             type Error = $crate::ConstructionError<$wrapper>;
             fn try_from(value: $inner) -> Result<Self, Self::Error> {
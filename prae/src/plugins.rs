@@ -1,2 +1,9 @@
-mod serde;
-mod std;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "hex")]
+pub mod hex;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod std;
+#[cfg(feature = "ts-rs")]
+mod ts_rs;
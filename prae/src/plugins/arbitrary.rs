@@ -0,0 +1,81 @@
+/// Implement [`arbitrary::Arbitrary`](::arbitrary::Arbitrary) for the wrapper.
+/// The arbitrary inner value is passed to
+/// [`Wrapper::new`](crate::Wrapper::new), and, if it doesn't pass wrapper's
+/// [`PROCESS`](crate::Wrapper::PROCESS) function, the generation fails with
+/// [`arbitrary::Error::IncorrectFormat`](::arbitrary::Error::IncorrectFormat).
+///
+/// For tight invariants, most of the arbitrary values will be rejected. In this
+/// case, you can pass the number of additional attempts to the plugin, e.g.
+/// `prae::impl_arbitrary(10)`. The number is bounded, so the generation can't
+/// loop forever, even if the invariant can never be satisfied.
+///
+/// If the invariant is so tight that the retries don't help, pass a custom
+/// generator of the inner values that only produces valid values, e.g.
+/// `prae::impl_arbitrary(with = |u| Ok(u.int_in_range(0..=127)? * 2))`. It
+/// receives the [`arbitrary::Unstructured`](::arbitrary::Unstructured) data
+/// and returns the inner value, which is then passed to
+/// [`Wrapper::new`](crate::Wrapper::new). The generator is expected to
+/// produce only valid values, so it isn't retried. If it produces an invalid
+/// value anyway, the generation fails with
+/// [`arbitrary::Error::IncorrectFormat`](::arbitrary::Error::IncorrectFormat),
+/// like it does without the generator.
+///
+/// Note that there is no support for `proptest` yet. The custom generator only
+/// works with [`arbitrary`](::arbitrary), so a `proptest` strategy has to be
+/// written by hand, e.g. by mapping a strategy of the inner values through
+/// [`Wrapper::new`](crate::Wrapper::new).
+///
+/// For this to work without a custom generator, the inner type of the wrapper
+/// must also implement this trait.
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+#[macro_export]
+macro_rules! impl_arbitrary {
+    ($wrapper:ident) => {
+        $crate::impl_arbitrary!(@generic $wrapper [] [] [], 0);
+    };
+    ($wrapper:ident, $($arg:tt)+) => {
+        $crate::impl_arbitrary!(@generic $wrapper [] [] [], $($arg)+);
+    };
+    (@generic $wrapper:ident $impl:tt $ty:tt $where:tt) => {
+        $crate::impl_arbitrary!(@generic $wrapper $impl $ty $where, 0);
+    };
+    // Must come before the retries arm, since `with = ...` is also an
+    // expression.
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*], with = $generate:expr) => {
+        impl<'a, $($impl)*> ::arbitrary::Arbitrary<'a> for $wrapper<$($ty)*>
+        where
+            $($where)*
+        {
+            fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+                let generate: fn(
+                    &mut ::arbitrary::Unstructured<'a>,
+                ) -> ::arbitrary::Result<<Self as $crate::Wrapper>::Inner> = $generate;
+                <Self as $crate::Wrapper>::new(generate(u)?)
+                    .map_err(|_| ::arbitrary::Error::IncorrectFormat)
+            }
+        }
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*], $retries:expr) => {
+        impl<'a, $($impl)*> ::arbitrary::Arbitrary<'a> for $wrapper<$($ty)*>
+        where
+            <Self as $crate::Wrapper>::Inner: ::arbitrary::Arbitrary<'a>,
+            $($where)*
+        {
+            fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+                let retries: usize = $retries;
+                for _ in 0..=retries {
+                    let value =
+                        <<Self as $crate::Wrapper>::Inner as ::arbitrary::Arbitrary>::arbitrary(u)?;
+                    if let Ok(wrapper) = <Self as $crate::Wrapper>::new(value) {
+                        return Ok(wrapper);
+                    }
+                }
+                Err(::arbitrary::Error::IncorrectFormat)
+            }
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <<Self as $crate::Wrapper>::Inner as ::arbitrary::Arbitrary>::size_hint(depth)
+            }
+        }
+    };
+}
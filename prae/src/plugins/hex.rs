@@ -0,0 +1,94 @@
+use crate::{ConstructionError, Wrapper};
+use core::error::Error;
+use core::fmt;
+
+/// Implement `to_hex` and `from_hex` methods for the wrapper. The `from_hex`
+/// method will fail if the string is not a valid hex or if the decoded bytes
+/// don't pass wrapper's [`PROCESS`](crate::Wrapper::PROCESS) function.
+///
+/// For this to work, the inner type of the wrapper must implement
+/// [`AsRef<[u8]>`](AsRef) and be constructible from a `Vec<u8>`.
+#[cfg(feature = "hex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hex")))]
+#[macro_export]
+macro_rules! impl_hex {
+    ($wrapper:ident) => {
+        $crate::impl_hex!(@generic $wrapper [] [] []);
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*]) => {
+        impl<$($impl)*> $wrapper<$($ty)*>
+        where
+            $($where)*
+        {
+            /// Encode the inner value as a lowercase hex string.
+            pub fn to_hex(&self) -> $crate::__private::String
+            where
+                <Self as $crate::Wrapper>::Inner: ::core::convert::AsRef<[u8]>,
+            {
+                ::hex::encode(
+                    <<Self as $crate::Wrapper>::Inner as ::core::convert::AsRef<[u8]>>::as_ref(
+                        &self.0,
+                    ),
+                )
+            }
+
+            /// Decode the hex string and construct a new wrapper from the
+            /// decoded bytes.
+            pub fn from_hex(s: &str) -> Result<Self, $crate::FromHexError<Self>>
+            where
+                $crate::__private::Vec<u8>: ::core::convert::Into<<Self as $crate::Wrapper>::Inner>,
+            {
+                let bytes = ::hex::decode(s).map_err($crate::FromHexError::Decode)?;
+                <Self as $crate::Wrapper>::new(bytes).map_err($crate::FromHexError::Construct)
+            }
+        }
+    };
+}
+
+/// An error that will be returned by the `from_hex` method generated by the
+/// [`impl_hex`](crate::impl_hex) plugin.
+pub enum FromHexError<W: Wrapper> {
+    /// The string is not a valid hex.
+    Decode(::hex::FromHexError),
+    /// The decoded bytes don't pass
+    /// [`Wrapper::PROCESS`](crate::Wrapper::PROCESS) function.
+    Construct(ConstructionError<W>),
+}
+
+impl<W> fmt::Debug for FromHexError<W>
+where
+    W: Wrapper + fmt::Debug,
+    W::Inner: fmt::Debug,
+    W::Error: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Decode(err) => f.debug_tuple("Decode").field(err).finish(),
+            Self::Construct(err) => f.debug_tuple("Construct").field(err).finish(),
+        }
+    }
+}
+
+impl<W> fmt::Display for FromHexError<W>
+where
+    W: Wrapper,
+    W::Inner: fmt::Debug,
+    W::Error: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Decode(err) => {
+                write!(f, "failed to decode hex for type {}: {}", W::NAME, err)
+            }
+            Self::Construct(err) => err.fmt(f),
+        }
+    }
+}
+
+impl<W> Error for FromHexError<W>
+where
+    W: Wrapper + fmt::Debug,
+    W::Inner: fmt::Debug,
+    W::Error: fmt::Display + fmt::Debug,
+{
+}
@@ -1,3 +1,8 @@
+use crate::Wrapper;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
 /// Implement [`serde::Serialize`](::serde::Serialize) and
 /// [`serde::Deserialize`](::serde::Deserialize) for the wrapper. Deserilization
 /// will fail if the value doesn't pass wrapper's
@@ -5,16 +10,133 @@
 ///
 /// For this to work, the inner type of the wrapper must also implement these
 /// traits.
+///
+/// Since the (de)serialization is delegated to the inner type, its
+/// representation is preserved. For example, if the inner type is an enum with
+/// `#[serde(tag = "type")]`, the wrapper is (de)serialized as an internally
+/// tagged enum.
+///
+/// If you need to omit the wrapper from the output with the
+/// `#[serde(skip_serializing_if = "...")]` attribute, use the [`skippable`
+/// closure](crate::define#skippable-closure) of the wrapper.
+///
+/// Field attributes of the structs that contain the wrapper, like
+/// `#[serde(rename = "...")]` or `#[serde(default)]`, work as usual, since
+/// they don't depend on the implementation of the wrapper. The only exception
+/// is `#[serde(with = "...")]` and its variations: they replace the plugin's
+/// implementation for the field, so they must construct the wrapper with
+/// [`Wrapper::new`](crate::Wrapper::new) to keep the validation.
+///
+/// Container attributes on the wrapper itself, like `#[serde(transparent)]`,
+/// are only understood by the derive macros. Since the plugin implements the
+/// traits instead of them, these attributes are rejected at compile time:
+/// ```compile_fail
+/// prae::define! {
+///     #[derive(Debug)]
+///     #[serde(transparent)]
+///     pub Username: String;
+///     plugins: [
+///         prae::impl_serde,
+///     ];
+/// }
+/// ```
+/// Deriving the traits together with the plugin is rejected too, because the
+/// implementations conflict:
+/// ```compile_fail
+/// prae::define! {
+///     #[derive(Debug, serde::Serialize)]
+///     pub Username: String;
+///     plugins: [
+///         prae::impl_serde,
+///     ];
+/// }
+/// ```
+///
+/// By default, the wrapper is (de)serialized transparently, as its inner
+/// value. Pass the `newtype` argument to (de)serialize it as a newtype struct
+/// named after the wrapper instead, the same way as
+/// `#[derive(Serialize, Deserialize)]` does for `struct Username(String)`.
+/// It only makes a difference for the formats that keep the names of the
+/// newtype structs:
+/// ```
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Username: String;
+///     plugins: [
+///         prae::impl_serde(newtype),
+///     ];
+/// }
+/// ```
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 #[macro_export]
 macro_rules! impl_serde {
     ($wrapper:ident) => {
-        impl<'de> ::serde::Deserialize<'de> for $wrapper
+        $crate::impl_serde!(@generic $wrapper [] [] []);
+    };
+    ($wrapper:ident, newtype) => {
+        $crate::impl_serde!(@generic $wrapper [] [] [], newtype);
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*], newtype) => {
+        impl<'de, $($impl)*> ::serde::Deserialize<'de> for $wrapper<$($ty)*>
         where
-            Self: $crate::Wrapper + ::std::fmt::Debug,
-            <Self as $crate::Wrapper>::Inner: ::serde::Deserialize<'de> + ::std::fmt::Debug,
-            <Self as $crate::Wrapper>::Error: ::std::fmt::Display + std::fmt::Debug,
+            Self: ::core::fmt::Debug,
+            <Self as $crate::Wrapper>::Inner: ::serde::Deserialize<'de> + ::core::fmt::Debug,
+            <Self as $crate::Wrapper>::Error: ::core::fmt::Display + ::core::fmt::Debug,
+            $($where)*
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                // The name is stored in the visitor, since the nested items
+                // can't use the type parameters of the wrapper.
+                struct NewtypeVisitor<T>(&'static str, ::core::marker::PhantomData<T>);
+                impl<'de, T: ::serde::Deserialize<'de>> ::serde::de::Visitor<'de> for NewtypeVisitor<T> {
+                    type Value = T;
+                    fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        write!(f, "newtype struct {}", self.0)
+                    }
+                    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<T, D::Error>
+                    where
+                        D: ::serde::Deserializer<'de>,
+                    {
+                        T::deserialize(deserializer)
+                    }
+                }
+                let value: <Self as $crate::Wrapper>::Inner = deserializer.deserialize_newtype_struct(
+                    <Self as $crate::Wrapper>::NAME,
+                    NewtypeVisitor(<Self as $crate::Wrapper>::NAME, ::core::marker::PhantomData),
+                )?;
+                <Self as $crate::Wrapper>::new(value)
+                    .map_err(|err| ::serde::de::Error::custom(err.original))
+            }
+        }
+        impl<$($impl)*> ::serde::Serialize for $wrapper<$($ty)*>
+        where
+            Self: ::core::fmt::Debug,
+            <Self as $crate::Wrapper>::Inner: ::serde::Serialize,
+            <Self as $crate::Wrapper>::Error: ::core::fmt::Display + ::core::fmt::Debug,
+            $($where)*
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                serializer.serialize_newtype_struct(<Self as $crate::Wrapper>::NAME, &self.0)
+            }
+        }
+    };
+    (@generic $wrapper:ident $impl:tt $ty:tt $where:tt) => {
+        $crate::impl_serde!(@impl $wrapper $impl $ty $where, err => err.original);
+    };
+    (@impl $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*], $err:ident => $message:expr) => {
+        impl<'de, $($impl)*> ::serde::Deserialize<'de> for $wrapper<$($ty)*>
+        where
+            Self: ::core::fmt::Debug,
+            <Self as $crate::Wrapper>::Inner: ::serde::Deserialize<'de> + ::core::fmt::Debug,
+            <Self as $crate::Wrapper>::Error: ::core::fmt::Display + ::core::fmt::Debug,
+            $($where)*
         {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
@@ -23,14 +145,130 @@ macro_rules! impl_serde {
                 <Self as $crate::Wrapper>::new(<Self as $crate::Wrapper>::Inner::deserialize(
                     deserializer,
                 )?)
+                .map_err(|$err| ::serde::de::Error::custom($message))
+            }
+        }
+        impl<$($impl)*> ::serde::Serialize for $wrapper<$($ty)*>
+        where
+            Self: ::core::fmt::Debug,
+            <Self as $crate::Wrapper>::Inner: ::serde::Serialize,
+            <Self as $crate::Wrapper>::Error: ::core::fmt::Display + ::core::fmt::Debug,
+            $($where)*
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                <Self as $crate::Wrapper>::Inner::serialize(&self.0, serializer)
+            }
+        }
+    };
+}
+
+/// Same as [`impl_serde`](crate::impl_serde), but the deserialization error
+/// contains the whole [`ConstructionError`](crate::ConstructionError) message
+/// (with the name of the wrapper and the value that caused the error) instead
+/// of just the original error:
+/// ```
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Username: String;
+///     ensure |u: &String| !u.is_empty();
+///     plugins: [
+///         prae::impl_serde_with_error,
+///     ];
+/// }
+///
+/// let err = serde_json::from_str::<Username>("\"\"").unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "failed to construct type Username from value \"\": value is invalid",
+/// );
+/// ```
+/// The value is printed the same way as in the
+/// [`Display`](core::fmt::Display) implementation of the error, so it's hidden
+/// if the wrapper uses the [`redact_value`
+/// flag](crate::define#redact_value-flag).
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[macro_export]
+macro_rules! impl_serde_with_error {
+    ($wrapper:ident) => {
+        $crate::impl_serde_with_error!(@generic $wrapper [] [] []);
+    };
+    (@generic $wrapper:ident $impl:tt $ty:tt $where:tt) => {
+        $crate::impl_serde!(@impl $wrapper $impl $ty $where, err => err);
+    };
+}
+
+/// Same as [`impl_serde`](crate::impl_serde), but the deserialization also
+/// accepts the inner value written as a string, which is then parsed with
+/// [`FromStr`](core::str::FromStr). It's useful for the loosely-typed
+/// producers that send both `8080` and `"8080"`:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Port: u16;
+///     ensure |p: &u16| *p >= 1024;
+///     plugins: [
+///         prae::impl_serde_lenient,
+///     ];
+/// }
+///
+/// let port: Port = serde_json::from_str("8080").unwrap();
+/// assert_eq!(port.get(), &8080);
+/// let port: Port = serde_json::from_str("\"8080\"").unwrap();
+/// assert_eq!(port.get(), &8080);
+/// // Both forms are validated.
+/// assert!(serde_json::from_str::<Port>("\"80\"").is_err());
+/// // The wrapper is always serialized as the inner value.
+/// assert_eq!(serde_json::to_string(&port).unwrap(), "8080");
+/// ```
+/// The value is deserialized as the inner type first, and only a string that
+/// doesn't deserialize as the inner type is parsed. Hence, if the inner type
+/// is itself deserialized from a string (e.g. `String`), the plugin behaves
+/// exactly like [`impl_serde`](crate::impl_serde).
+///
+/// For this to work, the inner type of the wrapper must implement
+/// [`DeserializeOwned`](::serde::de::DeserializeOwned) and
+/// [`FromStr`](core::str::FromStr). The value is buffered before the
+/// deserialization, so the format must be self-describing (e.g. JSON).
+#[cfg(feature = "serde_json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+#[macro_export]
+macro_rules! impl_serde_lenient {
+    ($wrapper:ident) => {
+        $crate::impl_serde_lenient!(@generic $wrapper [] [] []);
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*]) => {
+        impl<'de, $($impl)*> ::serde::Deserialize<'de> for $wrapper<$($ty)*>
+        where
+            Self: ::core::fmt::Debug,
+            <Self as $crate::Wrapper>::Inner:
+                ::serde::de::DeserializeOwned + ::core::str::FromStr + ::core::fmt::Debug,
+            <<Self as $crate::Wrapper>::Inner as ::core::str::FromStr>::Err: ::core::fmt::Display,
+            <Self as $crate::Wrapper>::Error: ::core::fmt::Display + ::core::fmt::Debug,
+            $($where)*
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                <Self as $crate::Wrapper>::new($crate::__private::deserialize_lenient::<
+                    <Self as $crate::Wrapper>::Inner,
+                    D,
+                >(deserializer)?)
                 .map_err(|err| ::serde::de::Error::custom(err.original))
             }
         }
-        impl ::serde::Serialize for $wrapper
+        impl<$($impl)*> ::serde::Serialize for $wrapper<$($ty)*>
         where
-            Self: $crate::Wrapper + ::std::fmt::Debug,
+            Self: ::core::fmt::Debug,
             <Self as $crate::Wrapper>::Inner: ::serde::Serialize,
-            <Self as $crate::Wrapper>::Error: ::std::fmt::Display + std::fmt::Debug,
+            <Self as $crate::Wrapper>::Error: ::core::fmt::Display + ::core::fmt::Debug,
+            $($where)*
         {
             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
             where
@@ -41,3 +279,99 @@ macro_rules! impl_serde {
         }
     };
 }
+
+/// Deserialize the value as `T`, falling back to parsing it if it's a string.
+#[cfg(feature = "serde_json")]
+pub fn deserialize_lenient<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: ::serde::de::DeserializeOwned + core::str::FromStr,
+    T::Err: core::fmt::Display,
+    D: ::serde::Deserializer<'de>,
+{
+    use ::serde::de::Error;
+
+    let value = <serde_json::Value as ::serde::Deserialize>::deserialize(deserializer)?;
+    match T::deserialize(&value) {
+        Ok(value) => Ok(value),
+        Err(err) => match value {
+            serde_json::Value::String(s) => s.parse().map_err(D::Error::custom),
+            _ => Err(D::Error::custom(err)),
+        },
+    }
+}
+
+/// Deserialize a sequence of the inner values into a vector of wrappers,
+/// reporting the index of the first value that doesn't pass
+/// [`Wrapper::PROCESS`](crate::Wrapper::PROCESS).
+///
+/// It's meant to be used with the `#[serde(deserialize_with = "...")]`
+/// attribute. The wrapper and its inner type don't need to implement
+/// `Deserialize` for it to work, but the inner type does:
+/// ```
+/// use serde::Deserialize;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Username: String;
+///     ensure |u: &String| !u.is_empty();
+/// }
+///
+/// #[derive(Debug, Deserialize)]
+/// struct Team {
+///     #[serde(deserialize_with = "prae::deserialize_vec")]
+///     members: Vec<Username>,
+/// }
+///
+/// let json = r#"{"members":["alice","","bob"]}"#;
+/// let err = serde_json::from_str::<Team>(json).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "invalid element at index 1: value is invalid at line 1 column 23",
+/// );
+/// ```
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub fn deserialize_vec<'de, W, D>(deserializer: D) -> Result<Vec<W>, D::Error>
+where
+    W: Wrapper,
+    W::Inner: ::serde::Deserialize<'de>,
+    W::Error: fmt::Display,
+    D: ::serde::Deserializer<'de>,
+{
+    struct SeqVisitor<W>(PhantomData<W>);
+
+    impl<'de, W> ::serde::de::Visitor<'de> for SeqVisitor<W>
+    where
+        W: Wrapper,
+        W::Inner: ::serde::Deserialize<'de>,
+        W::Error: fmt::Display,
+    {
+        type Value = Vec<W>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a sequence of values of type {}", W::NAME)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: ::serde::de::SeqAccess<'de>,
+        {
+            let mut wrappers = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+            while let Some(value) = seq.next_element::<W::Inner>()? {
+                match W::new(value) {
+                    Ok(wrapper) => wrappers.push(wrapper),
+                    Err(err) => {
+                        return Err(::serde::de::Error::custom(format_args!(
+                            "invalid element at index {}: {}",
+                            wrappers.len(),
+                            err.original,
+                        )))
+                    }
+                }
+            }
+            Ok(wrappers)
+        }
+    }
+
+    deserializer.deserialize_seq(SeqVisitor(PhantomData))
+}
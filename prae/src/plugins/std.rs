@@ -1,9 +1,20 @@
+use crate::{ConstructionError, Wrapper};
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+
 /// Implement [`Deref`](::core::ops::Deref) for the wrapper.
 #[macro_export]
 macro_rules! impl_deref {
     ($wrapper:ident) => {
-        impl ::core::ops::Deref for $wrapper {
-            type Target = <$wrapper as $crate::Wrapper>::Inner;
+        $crate::impl_deref!(@generic $wrapper [] [] []);
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*]) => {
+        impl<$($impl)*> ::core::ops::Deref for $wrapper<$($ty)*>
+        where
+            $($where)*
+        {
+            type Target = <Self as $crate::Wrapper>::Inner;
             fn deref(&self) -> &Self::Target {
                 &self.0
             }
@@ -12,14 +23,22 @@ macro_rules! impl_deref {
 }
 
 /// Implement [`Index`](::core::ops::Index) for the wrapper.
+///
+/// For this to work, the inner type of the wrapper must also implement this
+/// trait. The wrapper can be indexed with any index type the inner type
+/// accepts.
 #[macro_export]
 macro_rules! impl_index {
     ($wrapper:ident) => {
-        impl<Idx> ::core::ops::Index<Idx> for $wrapper
+        $crate::impl_index!(@generic $wrapper [] [] []);
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*]) => {
+        impl<Idx, $($impl)*> ::core::ops::Index<Idx> for $wrapper<$($ty)*>
         where
-            <$wrapper as $crate::Wrapper>::Inner: ::core::ops::Index<Idx>,
+            <Self as $crate::Wrapper>::Inner: ::core::ops::Index<Idx>,
+            $($where)*
         {
-            type Output = <<$wrapper as $crate::Wrapper>::Inner as ::core::ops::Index<Idx>>::Output;
+            type Output = <<Self as $crate::Wrapper>::Inner as ::core::ops::Index<Idx>>::Output;
             fn index(&self, idx: Idx) -> &Self::Output {
                 &self.0.index(idx)
             }
@@ -28,12 +47,19 @@ macro_rules! impl_index {
 }
 
 /// Implement [`Display`](::core::fmt::Display) for the wrapper.
+///
+/// For this to work, the inner type of the wrapper must also implement this
+/// trait.
 #[macro_export]
 macro_rules! impl_display {
     ($wrapper:ident) => {
-        impl ::core::fmt::Display for $wrapper
+        $crate::impl_display!(@generic $wrapper [] [] []);
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*]) => {
+        impl<$($impl)*> ::core::fmt::Display for $wrapper<$($ty)*>
         where
-            <$wrapper as $crate::Wrapper>::Inner: ::core::fmt::Display,
+            <Self as $crate::Wrapper>::Inner: ::core::fmt::Display,
+            $($where)*
         {
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                 self.0.fmt(f)
@@ -41,3 +67,745 @@ macro_rules! impl_display {
         }
     };
 }
+
+/// Implement [`Debug`](::core::fmt::Debug) for the wrapper that prints the
+/// name of the wrapper and the [`Debug`](::core::fmt::Debug) output of the
+/// inner value, e.g. `Username("bob")`:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     pub Username: String;
+///     plugins: [
+///         prae::impl_debug,
+///     ];
+/// }
+///
+/// let u = Username::new("bob").unwrap();
+/// assert_eq!(format!("{:?}", u), "Username(\"bob\")");
+/// ```
+/// The name is taken from [`Wrapper::NAME`](crate::Wrapper::NAME), so the
+/// output matches the error messages of the wrapper. The inner type of the
+/// wrapper must implement [`Debug`](::core::fmt::Debug). Don't derive
+/// [`Debug`](::core::fmt::Debug) for the wrapper when using this plugin,
+/// otherwise the implementations will conflict.
+#[macro_export]
+macro_rules! impl_debug {
+    ($wrapper:ident) => {
+        $crate::impl_debug!(@generic $wrapper [] [] []);
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*]) => {
+        impl<$($impl)*> ::core::fmt::Debug for $wrapper<$($ty)*>
+        where
+            <Self as $crate::Wrapper>::Inner: ::core::fmt::Debug,
+            $($where)*
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_tuple(<Self as $crate::Wrapper>::NAME)
+                    .field(&self.0)
+                    .finish()
+            }
+        }
+    };
+}
+
+/// Implement [`Display`](::core::fmt::Display) for the wrapper that prints at
+/// most the provided number of characters of the inner value, followed by `…`
+/// if the value is longer:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Comment: String;
+///     plugins: [
+///         prae::impl_display_truncated(8),
+///     ];
+/// }
+///
+/// let short = Comment::new("short").unwrap();
+/// assert_eq!(short.to_string(), "short");
+/// let long = Comment::new("a very long comment").unwrap();
+/// assert_eq!(long.to_string(), "a very l…");
+/// ```
+/// It's useful for logging potentially huge values. The full value is still
+/// accessible through [`Wrapper::get`](crate::Wrapper::get). The inner type of
+/// the wrapper must implement [`Display`](::core::fmt::Display).
+///
+/// The byte buffers (e.g. `Vec<u8>` or `Box<[u8]>`) don't implement
+/// [`Display`](::core::fmt::Display), so pass `hex` before the limit to print
+/// at most the provided number of bytes as a lowercase hex string instead. In
+/// this case, the inner type must implement [`AsRef<[u8]>`](AsRef):
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Payload: Vec<u8>;
+///     plugins: [
+///         prae::impl_display_truncated(hex, 2),
+///     ];
+/// }
+///
+/// let payload = Payload::new(vec![0xde, 0xad, 0xbe, 0xef]).unwrap();
+/// assert_eq!(payload.to_string(), "dead…");
+/// ```
+/// To shorten the values in the errors of the wrapper as well, use the
+/// [`truncate_value` flag](crate::define#truncate_value-flag).
+#[macro_export]
+macro_rules! impl_display_truncated {
+    ($wrapper:ident, hex, $max:expr) => {
+        $crate::impl_display_truncated!(@generic $wrapper [] [] [], hex, $max);
+    };
+    ($wrapper:ident, $max:expr) => {
+        $crate::impl_display_truncated!(@generic $wrapper [] [] [], $max);
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*], hex, $max:expr) => {
+        impl<$($impl)*> ::core::fmt::Display for $wrapper<$($ty)*>
+        where
+            <Self as $crate::Wrapper>::Inner: ::core::convert::AsRef<[u8]>,
+            $($where)*
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::__private::fmt_hex_truncated(
+                    ::core::convert::AsRef::<[u8]>::as_ref(&self.0),
+                    $max,
+                    f,
+                )
+            }
+        }
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*], $max:expr) => {
+        impl<$($impl)*> ::core::fmt::Display for $wrapper<$($ty)*>
+        where
+            <Self as $crate::Wrapper>::Inner: ::core::fmt::Display,
+            $($where)*
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::__private::fmt_truncated(&self.0, $max, f)
+            }
+        }
+    };
+}
+
+/// Write the [`Display`](fmt::Display) output of the value into the formatter,
+/// truncating it to `max` characters.
+#[doc(hidden)]
+pub fn fmt_truncated(
+    value: &impl fmt::Display,
+    max: usize,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    struct Truncate<'a, 'b> {
+        f: &'a mut fmt::Formatter<'b>,
+        remaining: usize,
+        truncated: bool,
+    }
+
+    impl fmt::Write for Truncate<'_, '_> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            if self.truncated {
+                return Ok(());
+            }
+            match s.char_indices().nth(self.remaining) {
+                Some((end, _)) => {
+                    self.truncated = true;
+                    self.remaining = 0;
+                    self.f.write_str(&s[..end])
+                }
+                None => {
+                    self.remaining -= s.chars().count();
+                    self.f.write_str(s)
+                }
+            }
+        }
+    }
+
+    let mut writer = Truncate {
+        f,
+        remaining: max,
+        truncated: false,
+    };
+    fmt::write(&mut writer, format_args!("{}", value))?;
+    if writer.truncated {
+        writer.f.write_str("…")?;
+    }
+    Ok(())
+}
+
+/// Write the [`Debug`](fmt::Debug) output of the value into the formatter,
+/// truncating it to `max` characters.
+#[doc(hidden)]
+pub fn fmt_debug_truncated(
+    value: &impl fmt::Debug,
+    max: usize,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    struct Debugged<'a, T>(&'a T);
+
+    impl<T: fmt::Debug> fmt::Display for Debugged<'_, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt(f)
+        }
+    }
+
+    fmt_truncated(&Debugged(value), max, f)
+}
+
+/// Write the bytes into the formatter as a lowercase hex string, truncating it
+/// to `max` bytes.
+#[doc(hidden)]
+pub fn fmt_hex_truncated(bytes: &[u8], max: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    struct Hex<'a>(&'a [u8]);
+
+    impl fmt::Display for Hex<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+        }
+    }
+
+    // Every byte takes exactly two characters.
+    fmt_truncated(&Hex(bytes), max.saturating_mul(2), f)
+}
+
+/// Add a `debug_pretty` method to the wrapper that returns the pretty-printed
+/// [`Debug`](::core::fmt::Debug) output (`{:#?}`) of the inner value:
+/// ```
+/// use prae::Wrapper;
+///
+/// #[derive(Debug)]
+/// pub struct User {
+///     name: String,
+/// }
+///
+/// prae::define! {
+///     pub ValidUser: User;
+///     ensure |u: &User| !u.name.is_empty();
+///     plugins: [
+///         prae::impl_debug_pretty,
+///     ];
+/// }
+///
+/// let user = ValidUser::new(User { name: "user".to_owned() }).unwrap_or_else(|_| panic!());
+/// assert_eq!(user.debug_pretty(), "User {\n    name: \"user\",\n}");
+/// ```
+/// It's handy for logging complex values in a readable form. The inner type
+/// of the wrapper must implement [`Debug`](::core::fmt::Debug).
+#[macro_export]
+macro_rules! impl_debug_pretty {
+    ($wrapper:ident) => {
+        $crate::impl_debug_pretty!(@generic $wrapper [] [] []);
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*]) => {
+        impl<$($impl)*> $wrapper<$($ty)*>
+        where
+            <Self as $crate::Wrapper>::Inner: ::core::fmt::Debug,
+            $($where)*
+        {
+            /// Get the pretty-printed debug representation of the inner value.
+            pub fn debug_pretty(&self) -> $crate::__private::String {
+                use ::core::fmt::Write;
+                let mut output = $crate::__private::String::new();
+                let _ = write!(output, "{:#?}", self.0);
+                output
+            }
+        }
+    };
+}
+
+/// Add an `as_str` method and implement [`AsRef<str>`](::core::convert::AsRef)
+/// for the wrapper whose inner type is `String`:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Username: String;
+///     plugins: [
+///         prae::impl_as_str,
+///     ];
+/// }
+///
+/// fn greet(name: impl AsRef<str>) -> String {
+///     format!("Hello, {}!", name.as_ref())
+/// }
+///
+/// let u = Username::new("user").unwrap();
+/// assert_eq!(u.as_str(), "user");
+/// assert_eq!(greet(u), "Hello, user!");
+/// ```
+/// The plugin doesn't compile for other inner types.
+#[macro_export]
+macro_rules! impl_as_str {
+    ($wrapper:ident) => {
+        $crate::impl_as_str!(@generic $wrapper [] [] []);
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*]) => {
+        impl<$($impl)*> $wrapper<$($ty)*>
+        where
+            $($where)*
+        {
+            /// Get the inner string as a string slice.
+            pub fn as_str(&self) -> &str {
+                $crate::__private::StringInner::as_string(&self.0).as_str()
+            }
+        }
+        impl<$($impl)*> ::core::convert::AsRef<str> for $wrapper<$($ty)*>
+        where
+            $($where)*
+        {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+    };
+}
+
+/// Add an `into_string` method to the wrapper whose inner type is `String`:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Username: String;
+///     plugins: [
+///         prae::impl_into_string,
+///     ];
+/// }
+///
+/// let u = Username::new("user").unwrap();
+/// assert_eq!(u.into_string(), "user");
+/// ```
+/// It's the same as [`Wrapper::into_inner`](crate::Wrapper::into_inner), but
+/// doesn't require the trait to be imported and makes the intention clear.
+/// Note that `From<Wrapper> for String` and `TryFrom<String> for Wrapper` are
+/// always implemented by [`define!`](crate::define). The plugin doesn't
+/// compile for other inner types.
+#[macro_export]
+macro_rules! impl_into_string {
+    ($wrapper:ident) => {
+        $crate::impl_into_string!(@generic $wrapper [] [] []);
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*]) => {
+        impl<$($impl)*> $wrapper<$($ty)*>
+        where
+            $($where)*
+        {
+            /// Unwrap the wrapper into the inner string.
+            pub fn into_string(self) -> $crate::__private::String {
+                $crate::__private::StringInner::into_string(self.0)
+            }
+        }
+    };
+}
+
+/// Implement [`Borrow<str>`](::core::borrow::Borrow) for the wrapper whose
+/// inner type is `String`, so it can be looked up by a string slice in the
+/// collections:
+/// ```
+/// use std::collections::HashMap;
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug, PartialEq, Eq, Hash)]
+///     pub Username: String;
+///     adjust |u| *u = u.trim().to_owned();
+///     plugins: [
+///         prae::impl_borrow_str,
+///     ];
+/// }
+///
+/// let mut ages = HashMap::new();
+/// ages.insert(Username::new("bob").unwrap(), 42);
+/// assert_eq!(ages.get("bob"), Some(&42));
+/// // The key is not adjusted, so it must be in the adjusted form.
+/// assert_eq!(ages.get(" bob "), None);
+/// ```
+/// [`Borrow`](::core::borrow::Borrow) requires the
+/// [`Eq`](::core::cmp::Eq), [`Ord`](::core::cmp::Ord) and
+/// [`Hash`](::core::hash::Hash) implementations of the wrapper to behave
+/// identically to the ones of `str`. The derived implementations do, but the
+/// ones generated by [`impl_hash_eq_by`](crate::impl_hash_eq_by) and
+/// [`impl_ord_by`](crate::impl_ord_by) usually don't, so don't combine them
+/// with this plugin. Also keep in mind that the looked up string doesn't go
+/// through the `adjust` closure, so it won't match a key if its adjusted form
+/// is different. The plugin doesn't compile for other inner types.
+#[macro_export]
+macro_rules! impl_borrow_str {
+    ($wrapper:ident) => {
+        $crate::impl_borrow_str!(@generic $wrapper [] [] []);
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*]) => {
+        impl<$($impl)*> ::core::borrow::Borrow<str> for $wrapper<$($ty)*>
+        where
+            $($where)*
+        {
+            fn borrow(&self) -> &str {
+                $crate::__private::StringInner::as_string(&self.0).as_str()
+            }
+        }
+    };
+}
+
+/// Restricts the plugins for string wrappers to the `String` inner type with a
+/// clear error message.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "the inner type of the wrapper must be `String`, but it's `{Self}`",
+    label = "this plugin only works with `String` wrappers"
+)]
+pub trait StringInner {
+    fn as_string(&self) -> &alloc::string::String;
+    fn into_string(self) -> alloc::string::String;
+}
+
+impl StringInner for alloc::string::String {
+    fn as_string(&self) -> &alloc::string::String {
+        self
+    }
+    fn into_string(self) -> alloc::string::String {
+        self
+    }
+}
+
+/// Implement [`PartialEq`](::core::cmp::PartialEq),
+/// [`Eq`](::core::cmp::Eq) and [`Hash`](::core::hash::Hash) for the wrapper
+/// based on the key returned by the provided closure, instead of the inner
+/// value itself:
+/// ```
+/// use std::collections::HashSet;
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Email: String;
+///     adjust |e| *e = e.trim().to_owned();
+///     plugins: [
+///         prae::impl_hash_eq_by(|e| e.to_lowercase()),
+///     ];
+/// }
+///
+/// let a = Email::new(" User@example.com").unwrap();
+/// let b = Email::new("user@EXAMPLE.com").unwrap();
+/// assert!(a == b);
+/// assert_eq!(HashSet::from([a, b]).len(), 1);
+/// ```
+/// The key must implement [`Eq`](::core::cmp::Eq) and
+/// [`Hash`](::core::hash::Hash). Don't derive these traits for the wrapper when
+/// using this plugin, otherwise the implementations will conflict.
+#[macro_export]
+macro_rules! impl_hash_eq_by {
+    ($wrapper:ident, $key:expr) => {
+        $crate::impl_hash_eq_by!(@generic $wrapper [] [] [], $key);
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*], $key:expr) => {
+        impl<$($impl)*> ::core::cmp::PartialEq for $wrapper<$($ty)*>
+        where
+            $($where)*
+        {
+            fn eq(&self, other: &Self) -> bool {
+                let key = $crate::__private::key_fn::<Self, _, _>($key);
+                key(&self.0) == key(&other.0)
+            }
+        }
+        impl<$($impl)*> ::core::cmp::Eq for $wrapper<$($ty)*>
+        where
+            $($where)*
+        {
+        }
+        impl<$($impl)*> ::core::hash::Hash for $wrapper<$($ty)*>
+        where
+            $($where)*
+        {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                let key = $crate::__private::key_fn::<Self, _, _>($key);
+                ::core::hash::Hash::hash(&key(&self.0), state);
+            }
+        }
+    };
+}
+
+/// Implement [`PartialEq`](::core::cmp::PartialEq) between the wrapper and
+/// its inner type, in both directions:
+/// ```
+/// use prae::Wrapper;
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub Username: String;
+///     plugins: [
+///         prae::impl_partial_eq_inner(&str, str),
+///     ];
+/// }
+///
+/// let u = Username::new("bob").unwrap();
+/// let bob = "bob".to_owned();
+/// assert!(u == bob);
+/// assert!(bob == u);
+/// assert!(u == "bob");
+/// assert!("bob" == u);
+/// assert!(u == *"bob");
+/// ```
+/// The optional arguments are additional types to compare with, which is
+/// useful when the inner type can be compared with a borrowed form of itself
+/// (e.g. `&str` and `str` for `String`). The macro can't inspect the inner
+/// type, so these types must be listed explicitly. Each of them must implement
+/// [`PartialEq`](::core::cmp::PartialEq) with the inner type in both
+/// directions.
+#[macro_export]
+macro_rules! impl_partial_eq_inner {
+    ($wrapper:ident $(, $other:ty)* $(,)?) => {
+        $crate::impl_partial_eq_inner!(@generic $wrapper [] [] [] $(, $other)*);
+    };
+    (@generic $wrapper:ident $impl:tt $ty:tt $where:tt $(, $other:ty)* $(,)?) => {
+        $crate::impl_partial_eq_inner!(@inner $wrapper $impl $ty $where);
+        $($crate::impl_partial_eq_inner!(@other $wrapper $impl $ty $where, $other);)*
+    };
+    (@inner $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*]) => {
+        impl<$($impl)*> ::core::cmp::PartialEq<<Self as $crate::Wrapper>::Inner>
+            for $wrapper<$($ty)*>
+        where
+            <Self as $crate::Wrapper>::Inner: ::core::cmp::PartialEq,
+            $($where)*
+        {
+            fn eq(&self, other: &<Self as $crate::Wrapper>::Inner) -> bool {
+                self.0 == *other
+            }
+        }
+        impl<$($impl)*> ::core::cmp::PartialEq<$wrapper<$($ty)*>>
+            for <$wrapper<$($ty)*> as $crate::Wrapper>::Inner
+        where
+            <$wrapper<$($ty)*> as $crate::Wrapper>::Inner: ::core::cmp::PartialEq,
+            $($where)*
+        {
+            fn eq(&self, other: &$wrapper<$($ty)*>) -> bool {
+                *self == other.0
+            }
+        }
+    };
+    (@other $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*], $other:ty) => {
+        impl<$($impl)*> ::core::cmp::PartialEq<$other> for $wrapper<$($ty)*>
+        where
+            $($where)*
+        {
+            fn eq(&self, other: &$other) -> bool {
+                ::core::cmp::PartialEq::eq(&self.0, other)
+            }
+        }
+        impl<$($impl)*> ::core::cmp::PartialEq<$wrapper<$($ty)*>> for $other
+        where
+            $($where)*
+        {
+            fn eq(&self, other: &$wrapper<$($ty)*>) -> bool {
+                ::core::cmp::PartialEq::eq(self, &other.0)
+            }
+        }
+    };
+}
+
+/// Implement [`PartialOrd`](::core::cmp::PartialOrd) and
+/// [`Ord`](::core::cmp::Ord) for the wrapper based on the key returned by the
+/// provided closure, instead of the inner value itself:
+/// ```
+/// use prae::Wrapper;
+///
+/// fn parse_version(v: &str) -> Vec<u32> {
+///     v.split('.').map(|n| n.parse().unwrap()).collect()
+/// }
+///
+/// prae::define! {
+///     #[derive(Debug)]
+///     pub SemVer: String;
+///     ensure |v: &String| v.split('.').all(|n| n.parse::<u32>().is_ok());
+///     plugins: [
+///         prae::impl_hash_eq_by(|v| parse_version(v)),
+///         prae::impl_ord_by(|v| parse_version(v)),
+///     ];
+/// }
+///
+/// let old = SemVer::new("1.9.0").unwrap();
+/// let new = SemVer::new("1.10.0").unwrap();
+/// assert!(old < new);
+/// ```
+/// The key must implement [`Ord`](::core::cmp::Ord). The wrapper must also
+/// implement [`Eq`](::core::cmp::Eq), which should be consistent with the
+/// ordering, so it's usually implemented with
+/// [`impl_hash_eq_by`](crate::impl_hash_eq_by) using the same key. Don't
+/// derive the ordering traits for the wrapper when using this plugin,
+/// otherwise the implementations will conflict.
+///
+/// Note that the wrappers never implement the ordering traits on their own,
+/// so a wrapper is only ordered if you opt in, either with this plugin or by
+/// deriving the traits:
+/// ```compile_fail
+/// prae::define! {
+///     pub Email: String;
+/// }
+///
+/// fn is_ordered<T: Ord>() {}
+/// is_ordered::<Email>();
+/// ```
+#[macro_export]
+macro_rules! impl_ord_by {
+    ($wrapper:ident, $key:expr) => {
+        $crate::impl_ord_by!(@generic $wrapper [] [] [], $key);
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*], $key:expr) => {
+        impl<$($impl)*> ::core::cmp::PartialOrd for $wrapper<$($ty)*>
+        where
+            $($where)*
+        {
+            fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+        impl<$($impl)*> ::core::cmp::Ord for $wrapper<$($ty)*>
+        where
+            $($where)*
+        {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                let key = $crate::__private::key_fn::<Self, _, _>($key);
+                ::core::cmp::Ord::cmp(&key(&self.0), &key(&other.0))
+            }
+        }
+    };
+}
+
+/// Implement [`PartialOrd`](::core::cmp::PartialOrd) for the wrapper based on
+/// the key returned by the provided closure. It's the same as
+/// [`impl_ord_by`](crate::impl_ord_by), but the key only needs to implement
+/// [`PartialOrd`](::core::cmp::PartialOrd) (e.g. a floating point number),
+/// and the wrapper only needs to implement
+/// [`PartialEq`](::core::cmp::PartialEq). It should compare by the same key,
+/// so the equality stays consistent with the ordering.
+#[macro_export]
+macro_rules! impl_partial_ord_by {
+    ($wrapper:ident, $key:expr) => {
+        $crate::impl_partial_ord_by!(@generic $wrapper [] [] [], $key);
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*], $key:expr) => {
+        impl<$($impl)*> ::core::cmp::PartialOrd for $wrapper<$($ty)*>
+        where
+            $($where)*
+        {
+            fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                let key = $crate::__private::key_fn::<Self, _, _>($key);
+                ::core::cmp::PartialOrd::partial_cmp(&key(&self.0), &key(&other.0))
+            }
+        }
+    };
+}
+
+/// Implement [`Default`](::core::default::Default) for the wrapper. The default
+/// value of the inner type is passed to [`Wrapper::new`](crate::Wrapper::new).
+///
+/// For this to work, the inner type of the wrapper must also implement this
+/// trait. Note that the implementation will panic if the default value of the
+/// inner type doesn't pass wrapper's [`PROCESS`](crate::Wrapper::PROCESS)
+/// function.
+#[macro_export]
+macro_rules! impl_default {
+    ($wrapper:ident) => {
+        $crate::impl_default!(@generic $wrapper [] [] []);
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*]) => {
+        impl<$($impl)*> ::core::default::Default for $wrapper<$($ty)*>
+        where
+            <Self as $crate::Wrapper>::Inner: ::core::default::Default,
+            $($where)*
+        {
+            fn default() -> Self {
+                match <Self as $crate::Wrapper>::new(
+                    <<Self as $crate::Wrapper>::Inner as ::core::default::Default>::default(),
+                ) {
+                    Ok(wrapper) => wrapper,
+                    Err(_) => panic!(
+                        "default value of type {} doesn't pass the validation",
+                        <Self as $crate::Wrapper>::NAME,
+                    ),
+                }
+            }
+        }
+    };
+}
+
+/// Implement [`FromStr`](::core::str::FromStr) for the wrapper. The string is
+/// first parsed into the inner type, and then the result is passed to
+/// [`Wrapper::new`](crate::Wrapper::new).
+///
+/// For this to work, the inner type of the wrapper must also implement this
+/// trait.
+#[macro_export]
+macro_rules! impl_from_str {
+    ($wrapper:ident) => {
+        $crate::impl_from_str!(@generic $wrapper [] [] []);
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*]) => {
+        impl<$($impl)*> ::core::str::FromStr for $wrapper<$($ty)*>
+        where
+            <Self as $crate::Wrapper>::Inner: ::core::str::FromStr,
+            $($where)*
+        {
+            type Err = $crate::FromStrError<Self>;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let value =
+                    <<Self as $crate::Wrapper>::Inner as ::core::str::FromStr>::from_str(s)
+                        .map_err($crate::FromStrError::Parse)?;
+                <Self as $crate::Wrapper>::new(value).map_err($crate::FromStrError::Construct)
+            }
+        }
+    };
+}
+
+/// An error that will be returned by the [`FromStr`](FromStr) implementation
+/// generated by the [`impl_from_str`](crate::impl_from_str) plugin.
+pub enum FromStrError<W>
+where
+    W: Wrapper,
+    W::Inner: FromStr,
+{
+    /// The string can't be parsed into the inner type.
+    Parse(<W::Inner as FromStr>::Err),
+    /// The parsed value doesn't pass
+    /// [`Wrapper::PROCESS`](crate::Wrapper::PROCESS) function.
+    Construct(ConstructionError<W>),
+}
+
+impl<W> fmt::Debug for FromStrError<W>
+where
+    W: Wrapper + fmt::Debug,
+    W::Inner: FromStr + fmt::Debug,
+    W::Error: fmt::Debug,
+    <W::Inner as FromStr>::Err: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(err) => f.debug_tuple("Parse").field(err).finish(),
+            Self::Construct(err) => f.debug_tuple("Construct").field(err).finish(),
+        }
+    }
+}
+
+impl<W> fmt::Display for FromStrError<W>
+where
+    W: Wrapper,
+    W::Inner: FromStr + fmt::Debug,
+    W::Error: fmt::Display,
+    <W::Inner as FromStr>::Err: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(err) => write!(f, "failed to parse type {}: {}", W::NAME, err),
+            Self::Construct(err) => err.fmt(f),
+        }
+    }
+}
+
+impl<W> Error for FromStrError<W>
+where
+    W: Wrapper + fmt::Debug,
+    W::Inner: FromStr + fmt::Debug,
+    W::Error: fmt::Display + fmt::Debug,
+    <W::Inner as FromStr>::Err: fmt::Display + fmt::Debug,
+{
+}
@@ -0,0 +1,51 @@
+/// Implement [`ts_rs::TS`](::ts_rs::TS) for the wrapper. The TypeScript
+/// representation of the wrapper is the same as the representation of it's
+/// inner type (e.g. `string` for a `String`-backed wrapper).
+///
+/// For this to work, the inner type of the wrapper must also implement this
+/// trait.
+#[cfg(feature = "ts-rs")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ts-rs")))]
+#[macro_export]
+macro_rules! impl_ts {
+    ($wrapper:ident) => {
+        $crate::impl_ts!(@generic $wrapper [] [] []);
+    };
+    (@generic $wrapper:ident [$($impl:tt)*] [$($ty:tt)*] [$($where:tt)*]) => {
+        impl<$($impl)*> ::ts_rs::TS for $wrapper<$($ty)*>
+        where
+            <Self as $crate::Wrapper>::Inner: ::ts_rs::TS,
+            $($where)*
+        {
+            type WithoutGenerics = Self;
+            type OptionInnerType = Self;
+            fn name(cfg: &::ts_rs::Config) -> String {
+                <<Self as $crate::Wrapper>::Inner as ::ts_rs::TS>::name(cfg)
+            }
+            fn inline(cfg: &::ts_rs::Config) -> String {
+                <<Self as $crate::Wrapper>::Inner as ::ts_rs::TS>::inline(cfg)
+            }
+            fn inline_flattened(cfg: &::ts_rs::Config) -> String {
+                <<Self as $crate::Wrapper>::Inner as ::ts_rs::TS>::inline_flattened(cfg)
+            }
+            fn visit_dependencies(v: &mut impl ::ts_rs::TypeVisitor)
+            where
+                Self: 'static,
+            {
+                <<Self as $crate::Wrapper>::Inner as ::ts_rs::TS>::visit_dependencies(v);
+            }
+            fn visit_generics(v: &mut impl ::ts_rs::TypeVisitor)
+            where
+                Self: 'static,
+            {
+                <<Self as $crate::Wrapper>::Inner as ::ts_rs::TS>::visit_generics(v);
+            }
+            fn decl(_: &::ts_rs::Config) -> String {
+                panic!("{} cannot be declared", stringify!($wrapper))
+            }
+            fn decl_concrete(_: &::ts_rs::Config) -> String {
+                panic!("{} cannot be declared", stringify!($wrapper))
+            }
+        }
+    };
+}
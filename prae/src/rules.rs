@@ -0,0 +1,141 @@
+//! Ready-made checks for the [`ensure` closure](crate::define#ensure-closure).
+//!
+//! Every rule is a plain generic function, so it coerces to the `fn` pointer
+//! expected by [`define!`](crate::define) and can be passed to `ensure`
+//! directly. The bounds are given as const generic arguments, because a
+//! function that returns a closure capturing them couldn't be coerced:
+//! ```
+//! use prae::Wrapper;
+//!
+//! prae::define! {
+//!     #[derive(Debug)]
+//!     pub Username: String;
+//!     ensure prae::rules::len_between::<3, 20>;
+//! }
+//!
+//! prae::define! {
+//!     #[derive(Debug)]
+//!     pub Percent: u8;
+//!     ensure prae::rules::in_range::<0, 100>;
+//! }
+//!
+//! assert!(Username::new("joe").is_ok());
+//! assert!(Username::new("jo").is_err());
+//! assert!(Percent::new(100).is_ok());
+//! assert!(Percent::new(101).is_err());
+//! ```
+//! Several rules can be combined inside a closure:
+//! ```
+//! use prae::rules;
+//!
+//! prae::define! {
+//!     pub Tags: Vec<String>;
+//!     ensure |tags| rules::non_empty(tags) && tags.iter().all(rules::len_between::<1, 16>);
+//! }
+//! ```
+
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet, VecDeque};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Values that have a length, used by [`non_empty`] and [`len_between`].
+///
+/// The length of strings is measured in bytes, just like [`str::len`].
+pub trait Length {
+    /// Return the length of the value.
+    fn length(&self) -> usize;
+}
+
+macro_rules! impl_length {
+    (for<$($param:ident),*> $ty:ty) => {
+        impl<$($param),*> Length for $ty {
+            fn length(&self) -> usize {
+                self.len()
+            }
+        }
+    };
+    ($ty:ty) => {
+        impl_length!(for<> $ty);
+    };
+}
+
+impl_length!(str);
+impl_length!(String);
+impl_length!(Box<str>);
+impl_length!(for<T> [T]);
+impl_length!(for<T> Vec<T>);
+impl_length!(for<T> Box<[T]>);
+impl_length!(for<T> VecDeque<T>);
+impl_length!(for<T> BTreeSet<T>);
+impl_length!(for<K, V> BTreeMap<K, V>);
+#[cfg(feature = "std")]
+impl_length!(for<T, S> std::collections::HashSet<T, S>);
+#[cfg(feature = "std")]
+impl_length!(for<K, V, S> std::collections::HashMap<K, V, S>);
+
+impl<T, const N: usize> Length for [T; N] {
+    fn length(&self) -> usize {
+        N
+    }
+}
+
+impl<T: Length + ?Sized> Length for &T {
+    fn length(&self) -> usize {
+        (**self).length()
+    }
+}
+
+/// Check that the value is not empty.
+pub fn non_empty<T: Length + ?Sized>(value: &T) -> bool {
+    value.length() != 0
+}
+
+/// Check that the length of the value is within `MIN..=MAX`.
+pub fn len_between<const MIN: usize, const MAX: usize>(value: &(impl Length + ?Sized)) -> bool {
+    (MIN..=MAX).contains(&value.length())
+}
+
+/// Integers, used by [`in_range`].
+///
+/// The trait is sealed and implemented for every primitive integer type.
+pub trait Integer: Copy + sealed::Sealed {
+    /// Convert the integer into `i128`, or return `None` if it doesn't fit.
+    fn to_i128(self) -> Option<i128>;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl sealed::Sealed for $ty {}
+            impl Integer for $ty {
+                fn to_i128(self) -> Option<i128> {
+                    i128::try_from(self).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Check that the integer is within `MIN..=MAX`.
+///
+/// The bounds are `i128`, but every integer type is supported. A `u128` value
+/// that doesn't fit into `i128` is always out of range. Negative bounds must be
+/// wrapped in braces, e.g. `in_range::<{ -10 }, 10>`.
+///
+/// Only integers are accepted, so a check of e.g. a `bool` doesn't compile:
+/// ```compile_fail
+/// prae::define! {
+///     pub Flag: bool;
+///     ensure prae::rules::in_range::<0, 1>;
+/// }
+/// ```
+pub fn in_range<const MIN: i128, const MAX: i128>(value: &impl Integer) -> bool {
+    matches!(value.to_i128(), Some(v) if (MIN..=MAX).contains(&v))
+}
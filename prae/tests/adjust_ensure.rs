@@ -34,3 +34,32 @@ fn mutation_succeeds_for_valid_data() {
     un.mutate(|u| *u = "  new user  ".to_owned()).unwrap();
     assert_eq!(un.get(), "new user");
 }
+
+#[test]
+fn setting_fails_for_invalid_data() {
+    let mut un = Username::new("user").unwrap();
+    assert_matches!(un.try_set("  "), Err(prae::ConstructionError { .. }));
+    assert_eq!(un.get(), "user");
+}
+
+#[test]
+fn setting_succeeds_for_valid_data() {
+    let mut un = Username::new("user").unwrap();
+    un.set("  new user  ");
+    assert_eq!(un.get(), "new user");
+}
+
+#[test]
+#[should_panic(expected = "failed to construct type Username from value \"\": value is invalid")]
+fn setting_panics_for_invalid_data() {
+    let mut un = Username::new("user").unwrap();
+    un.set("  ");
+}
+
+#[test]
+fn adjust_runs_without_validation() {
+    let mut value = "  ".to_owned();
+    Username::ADJUST(&mut value);
+    assert_eq!(value, "");
+    assert!(!Username::is_valid(&value));
+}
@@ -0,0 +1,97 @@
+use prae::Wrapper;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PhoneError {
+    Unparseable,
+    TooShort,
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Phone: String;
+    adjust(PhoneError) |p: &mut String| {
+        let digits: String = p.chars().filter(|c| !matches!(c, ' ' | '-')).collect();
+        if !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(PhoneError::Unparseable);
+        }
+        *p = digits;
+        Ok(())
+    };
+    validate(PhoneError) |p: &String| {
+        if p.len() < 7 {
+            Err(PhoneError::TooShort)
+        } else {
+            Ok(())
+        }
+    };
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct NotANumber;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Number: String;
+    adjust(NotANumber) |n: &mut String| {
+        let parsed: i64 = n.trim().parse().map_err(|_| NotANumber)?;
+        *n = parsed.to_string();
+        Ok(())
+    };
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Code: String;
+    adjust(&'static str) |c: &mut String| {
+        if c.is_ascii() {
+            c.make_ascii_uppercase();
+            Ok(())
+        } else {
+            Err("code is not ASCII")
+        }
+    };
+    canonicalize |c: &mut String| c.retain(|c| c != ' ');
+    ensure("code is too long") |c: &String| c.len() <= 4;
+}
+
+#[test]
+fn fallible_adjust_succeeds() {
+    let p = Phone::new("555 12-34").unwrap();
+    assert_eq!(p.get(), "5551234");
+}
+
+#[test]
+fn fallible_adjust_short_circuits() {
+    let err = Phone::new("555-12-3x").unwrap_err();
+    assert_eq!(err.original, PhoneError::Unparseable);
+    assert_eq!(err.value, "555-12-3x");
+}
+
+#[test]
+fn validation_runs_after_fallible_adjust() {
+    let err = Phone::new("55-5").unwrap_err();
+    assert_eq!(err.original, PhoneError::TooShort);
+    assert_eq!(err.value, "555");
+}
+
+#[test]
+fn fallible_adjust_runs_on_mutation() {
+    let mut p = Phone::new("5551234").unwrap();
+    assert!(p.mutate(|p| p.push('y')).is_err());
+    assert_eq!(p.get(), "5551234");
+    p.mutate(|p| p.push_str("-5")).unwrap();
+    assert_eq!(p.get(), "55512345");
+}
+
+#[test]
+fn fallible_adjust_without_validation() {
+    assert_eq!(Number::new(" 042 ").unwrap().get(), "42");
+    assert_eq!(Number::new("four").unwrap_err().original, NotANumber);
+}
+
+#[test]
+fn fallible_adjust_with_canonicalize_and_custom_ensure() {
+    assert_eq!(Code::new("a b").unwrap().get(), "AB");
+    assert_eq!(Code::new("ä").unwrap_err().original, "code is not ASCII");
+    assert_eq!(Code::new("abcde").unwrap_err().original, "code is too long");
+}
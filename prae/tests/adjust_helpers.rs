@@ -0,0 +1,52 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Text: String;
+    adjust prae::adjust::trim;
+    ensure |t: &String| !t.is_empty();
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Tags: Vec<String>;
+    adjust prae::adjust::sort_dedup;
+}
+
+#[test]
+fn trim_removes_whitespace_on_both_sides() {
+    assert_eq!(
+        Text::new(" \t hello world \n").unwrap().get(),
+        "hello world"
+    );
+    assert!(Text::new("   ").is_err());
+}
+
+#[test]
+fn trim_helpers_keep_the_buffer() {
+    let mut s = String::with_capacity(32);
+    s.push_str("  value  ");
+    let ptr = s.as_ptr();
+
+    prae::adjust::trim_end(&mut s);
+    assert_eq!(s, "  value");
+    prae::adjust::trim_start(&mut s);
+    assert_eq!(s, "value");
+    assert_eq!(s.as_ptr(), ptr);
+    assert_eq!(s.capacity(), 32);
+}
+
+#[test]
+fn trim_handles_unicode_whitespace() {
+    let mut s = "\u{3000}привет\u{2003}".to_owned();
+    prae::adjust::trim(&mut s);
+    assert_eq!(s, "привет");
+}
+
+#[test]
+fn sort_dedup_works_on_mutation() {
+    let mut tags = Tags::new(vec!["b".to_owned(), "a".to_owned(), "b".to_owned()]).unwrap();
+    assert_eq!(tags.get(), &["a", "b"]);
+    tags.mutate(|t| t.push("a".to_owned())).unwrap();
+    assert_eq!(tags.get(), &["a", "b"]);
+}
@@ -0,0 +1,41 @@
+use prae::Wrapper;
+use std::borrow::Cow;
+
+#[derive(Debug, Clone)]
+pub struct Range {
+    start: u32,
+    end: u32,
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub ValidRange: Range;
+    ensure |r| r.start <= r.end;
+}
+
+fn widen(r: Cow<'_, Range>) -> Cow<'_, Range> {
+    if r.start == 0 {
+        r
+    } else {
+        Cow::Owned(Range {
+            start: 0,
+            end: r.end,
+        })
+    }
+}
+
+#[test]
+fn as_cow_borrows_inner_value() {
+    let r = ValidRange::new(Range { start: 0, end: 5 }).unwrap();
+    let cow = r.as_cow();
+    assert!(matches!(cow, Cow::Borrowed(v) if std::ptr::eq(v, r.get())));
+    assert!(matches!(widen(cow), Cow::Borrowed(_)));
+}
+
+#[test]
+fn as_cow_clones_only_when_needed() {
+    let r = ValidRange::new(Range { start: 2, end: 5 }).unwrap();
+    let cow = widen(r.as_cow());
+    assert!(matches!(cow, Cow::Owned(Range { start: 0, end: 5 })));
+    assert_eq!(r.get().start, 2);
+}
@@ -0,0 +1,84 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub CanonicalSet: Vec<u32>;
+    canonicalize |set: &mut Vec<u32>| {
+        set.sort_unstable();
+        set.dedup();
+    };
+    ensure |set: &Vec<u32>| !set.is_empty();
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Tags: Vec<String>;
+    adjust |tags: &mut Vec<String>| {
+        for tag in tags.iter_mut() {
+            *tag = tag.to_lowercase();
+        }
+    };
+    canonicalize |tags: &mut Vec<String>| {
+        tags.sort();
+        tags.dedup();
+    };
+    ensure("tags must not be empty") |tags: &Vec<String>| !tags.is_empty();
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    id: u32,
+    name: &'static str,
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub UniqueItems: Vec<Item>;
+    ensure |items: &Vec<Item>| items.windows(2).all(|w| w[0].id != w[1].id);
+}
+
+#[test]
+fn unsorted_input_becomes_sorted() {
+    let set = CanonicalSet::new(vec![5, 3, 9, 3, 1]).unwrap();
+    assert_eq!(set.get(), &[1, 3, 5, 9]);
+}
+
+#[test]
+fn canonicalize_runs_on_mutation() {
+    let mut set = CanonicalSet::new(vec![2, 1]).unwrap();
+    set.mutate(|s| s.push(0)).unwrap();
+    assert_eq!(set.get(), &[0, 1, 2]);
+    assert!(set.mutate(|s| s.clear()).is_err());
+    assert_eq!(set.get(), &[0, 1, 2]);
+}
+
+#[test]
+fn canonicalize_runs_after_adjust() {
+    let tags = Tags::new(vec!["B".to_owned(), "a".to_owned(), "b".to_owned()]).unwrap();
+    assert_eq!(tags.get(), &["a", "b"]);
+    let err = Tags::new(vec![]).unwrap_err();
+    assert_eq!(err.original, "tags must not be empty");
+}
+
+#[test]
+fn sort_by_key_checked_sorts_in_place() {
+    let mut items =
+        UniqueItems::new(vec![Item { id: 2, name: "b" }, Item { id: 1, name: "a" }]).unwrap();
+    items.sort_by_key_checked(|i| i.id).unwrap();
+    assert_eq!(items.get()[0].name, "a");
+    assert_eq!(items.get()[1].name, "b");
+}
+
+#[test]
+fn sort_by_key_checked_revalidates() {
+    let mut items = UniqueItems::new(vec![
+        Item { id: 1, name: "a" },
+        Item { id: 2, name: "b" },
+        Item { id: 1, name: "c" },
+    ])
+    .unwrap();
+    // Sorting by id makes the duplicates consecutive, which is invalid.
+    let err = items.sort_by_key_checked(|i| i.id).unwrap_err();
+    assert_eq!(err.new_value[0].id, err.new_value[1].id);
+    assert_eq!(items.get()[2].name, "c");
+}
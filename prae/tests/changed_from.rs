@@ -0,0 +1,32 @@
+use prae::Wrapper;
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    host: String,
+    port: u16,
+}
+
+prae::define! {
+    #[derive(Debug)]
+    ValidConfig: Config;
+    ensure |c| !c.host.is_empty();
+}
+
+fn config(host: &str, port: u16) -> ValidConfig {
+    ValidConfig::new(Config {
+        host: host.to_owned(),
+        port,
+    })
+    .unwrap()
+}
+
+#[test]
+fn changed_from_detects_changes() {
+    assert!(config("localhost", 80).changed_from(&config("localhost", 8080)));
+    assert!(config("localhost", 80).changed_from(&config("example.com", 80)));
+}
+
+#[test]
+fn changed_from_ignores_equal_values() {
+    assert!(!config("localhost", 80).changed_from(&config("localhost", 80)));
+}
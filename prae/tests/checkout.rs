@@ -0,0 +1,42 @@
+use assert_matches::assert_matches;
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    Numbers: Vec<u64>;
+    adjust |n| n.sort_unstable();
+    ensure |n| !n.is_empty();
+}
+
+#[test]
+fn commit_succeeds_for_valid_data() {
+    let mut nums = Numbers::new([1, 2]).unwrap();
+    let mut checkout = nums.checkout();
+    checkout.push(0);
+    checkout.commit().unwrap();
+    assert_eq!(nums.get(), &[0, 1, 2]);
+}
+
+#[test]
+fn commit_fails_and_reverts_for_invalid_data() {
+    let mut nums = Numbers::new([1, 2]).unwrap();
+    let mut checkout = nums.checkout();
+    checkout.clear();
+    assert_matches!(
+        checkout.commit(),
+        Err(prae::MutationError { old_value, new_value, .. })
+            if old_value.as_deref() == Some(&[1, 2][..]) && new_value.is_empty()
+    );
+    assert_eq!(nums.get(), &[1, 2]);
+}
+
+#[test]
+fn drop_without_commit_reverts() {
+    let mut nums = Numbers::new([1, 2]).unwrap();
+    {
+        let mut checkout = nums.checkout();
+        checkout.push(3);
+        assert_eq!(*checkout, [1, 2, 3]);
+    }
+    assert_eq!(nums.get(), &[1, 2]);
+}
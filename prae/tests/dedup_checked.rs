@@ -0,0 +1,36 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Tags: Vec<String>;
+    adjust |t| t.sort();
+    ensure |t| t.windows(2).all(|w| w[0] != w[1]);
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Pairs: Vec<u8>;
+    ensure |p| p.len() % 2 == 0;
+}
+
+fn tags(t: &[&str]) -> Vec<String> {
+    t.iter().map(|t| t.to_string()).collect()
+}
+
+#[test]
+fn dedup_repairs_invalid_value() {
+    let mut t = Tags::new_unprocessed(tags(&["a", "b", "b", "c"]));
+    assert!(!Tags::is_valid(t.get()));
+    t.dedup_checked().unwrap();
+    assert_eq!(t.get(), &tags(&["a", "b", "c"]));
+}
+
+#[test]
+fn dedup_fails_if_result_is_invalid() {
+    let mut p = Pairs::new(vec![1, 1, 2, 3]).unwrap();
+    assert!(p.dedup_checked().is_err());
+    assert_eq!(p.get(), &vec![1, 1, 2, 3]);
+    let mut p = Pairs::new(vec![1, 1, 2, 2]).unwrap();
+    p.dedup_checked().unwrap();
+    assert_eq!(p.get(), &vec![1, 2]);
+}
@@ -0,0 +1,77 @@
+#[cfg(feature = "derive")]
+mod tests {
+    use assert_matches::assert_matches;
+    use prae::Wrapper;
+
+    fn trim(value: &mut String) {
+        *value = value.trim().to_owned();
+    }
+
+    fn lowercase(value: &mut String) {
+        *value = value.to_lowercase();
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn not_empty(value: &String) -> bool {
+        !value.is_empty()
+    }
+
+    #[derive(Debug, prae::Validate)]
+    #[prae(adjust = trim, adjust = lowercase, ensure = not_empty)]
+    pub struct Username(String);
+
+    #[test]
+    fn construction_adjusts_and_ensures() {
+        let u = Username::new(" UsEr ").unwrap();
+        assert_eq!(u.get(), "user");
+        assert_matches!(Username::new("   "), Err(e) if e.original == "value is invalid");
+        assert_eq!(Username::NAME, "Username");
+    }
+
+    #[test]
+    fn mutation_revalidates() {
+        let mut u = Username::new("user").unwrap();
+        assert!(u.try_set("  ").is_err());
+        assert_eq!(u.get(), "user");
+        u.set(" OTHER ");
+        assert_eq!(u.get(), "other");
+    }
+
+    #[test]
+    fn external_traits_are_implemented() {
+        let u: Username = String::from("user").try_into().unwrap();
+        let inner: String = u.into();
+        assert_eq!(inner, "user");
+    }
+
+    #[derive(Debug, prae::Validate)]
+    #[prae(ensure = |v: &u8| *v > 0, message = "value is zero")]
+    pub struct NonZero(u8);
+
+    #[test]
+    fn ensure_message_is_used() {
+        assert_matches!(NonZero::new(0u8), Err(e) if e.original == "value is zero");
+        assert_eq!(NonZero::new(1u8).unwrap().into_inner(), 1);
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct OddError;
+
+    #[derive(Debug, prae::Validate)]
+    #[prae(validate = |v: &u32| if v % 2 == 1 { Ok(()) } else { Err(OddError) }, error = OddError)]
+    pub struct Odd(u32);
+
+    #[test]
+    fn validate_uses_custom_error() {
+        assert_matches!(Odd::new(2u32), Err(e) if e.original == OddError);
+        assert_eq!(*Odd::new(3u32).unwrap().get(), 3);
+    }
+
+    #[derive(Debug, prae::Validate)]
+    pub struct Anything(i32);
+
+    #[test]
+    fn no_closures_accepts_everything() {
+        assert_eq!(*Anything::new(-1).unwrap().get(), -1);
+    }
+}
@@ -0,0 +1,57 @@
+use prae::{Draft, Wrapper};
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    adjust |u| *u = u.trim().to_owned();
+    ensure |u: &String| !u.is_empty();
+}
+
+type UsernameDraft = Draft<Username>;
+
+struct User {
+    name: String,
+}
+
+prae::define! {
+    ValidUser: User;
+    ensure |u| !u.name.is_empty();
+}
+
+#[test]
+fn draft_is_default_when_inner_is_default() {
+    let draft = UsernameDraft::default();
+    assert_eq!(draft.get(), "");
+}
+
+#[test]
+fn draft_can_be_invalid_until_built() {
+    let mut draft = UsernameDraft::new("  ");
+    assert!(draft.clone().build().is_err());
+    draft.push_str("user  ");
+    let u = draft.build().unwrap();
+    assert_eq!(u.get(), "user");
+}
+
+#[test]
+fn build_returns_construction_error() {
+    let err = UsernameDraft::new("   ").build().unwrap_err();
+    assert_eq!(err.value, "");
+}
+
+#[test]
+fn wrapper_turns_into_draft() {
+    let u = Username::new("user").unwrap();
+    let mut draft = u.into_draft();
+    draft.get_mut().clear();
+    assert_eq!(draft.into_inner(), "");
+}
+
+#[test]
+fn draft_works_without_default_and_clone() {
+    let mut draft = Draft::<ValidUser>::new(User {
+        name: String::new(),
+    });
+    draft.name.push_str("user");
+    assert!(draft.build().is_ok());
+}
@@ -45,3 +45,19 @@ fn mutation_fails_for_invalid_data() {
         Err(prae::MutationError { .. })
     )
 }
+
+prae::define! {
+    #[derive(Debug)]
+    pub Password: String;
+    ensure("password is too short") |p| p.len() >= 8;
+}
+
+#[test]
+fn construction_error_uses_custom_message() {
+    let err = Password::new("short").unwrap_err();
+    assert_eq!(err.original, "password is too short");
+    assert_eq!(
+        err.to_string(),
+        "failed to construct type Password from value \"short\": password is too short"
+    );
+}
@@ -0,0 +1,68 @@
+use prae::{EnsureError, MapOriginalError, Wrapper};
+use std::fmt::Debug;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    adjust prae::adjust::trim;
+    ensure_detailed("username is empty") |u: &String| !u.is_empty();
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Even: u32;
+    ensure_detailed |v| v % 2 == 0;
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Password: String;
+    ensure_detailed |p: &String| p.len() >= 8;
+    redact_value;
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub NonEmpty<T: Debug>: Vec<T>;
+    ensure_detailed("vector is empty") |v: &Vec<T>| !v.is_empty();
+}
+
+#[test]
+fn error_carries_message_and_value() {
+    let err = Username::new("   ").map_original().unwrap_err();
+    assert_eq!(
+        err,
+        EnsureError {
+            message: "username is empty",
+            value: "\"\"".to_owned(),
+        }
+    );
+    assert!(Username::new(" user ").is_ok());
+}
+
+#[test]
+fn default_message_is_used() {
+    let err = Even::new(3u32).map_original().unwrap_err();
+    assert_eq!(err.message, "value is invalid");
+    assert_eq!(err.to_string(), "value is invalid (got 3)");
+}
+
+#[test]
+fn snapshot_respects_redact_value() {
+    let err = Password::new("hunter2").map_original().unwrap_err();
+    assert_eq!(err.value, "<redacted>");
+}
+
+#[test]
+fn mutation_errors_carry_the_value() {
+    let mut even = Even::new(2u32).unwrap();
+    let err = even.mutate(|v| *v += 1).unwrap_err();
+    assert_eq!(err.original.value, "3");
+}
+
+#[test]
+fn generic_wrappers_are_supported() {
+    let err = NonEmpty::<u8>::new(vec![]).map_original().unwrap_err();
+    assert_eq!(err.message, "vector is empty");
+    assert_eq!(err.value, "[]");
+}
@@ -0,0 +1,64 @@
+#![deny(missing_docs)]
+//! Tests of the `ensure_enum` checks.
+
+use assert_matches::assert_matches;
+use prae::Wrapper;
+
+prae::define! {
+    /// A name of a user.
+    #[derive(Debug)]
+    pub Username: String;
+    adjust |u| *u = u.trim().to_owned();
+    ensure_enum(
+        /// An error of the `Username`.
+        #[derive(Hash)]
+        UsernameError
+    ) {
+        /// The username is empty.
+        Empty => |u| !u.is_empty(),
+        /// The username is longer than 8 bytes.
+        TooLong => |u| u.len() <= 8,
+        /// The username contains non-ASCII characters.
+        NotAscii => |u| u.is_ascii(),
+    };
+}
+
+#[test]
+fn construction_succeeds_with_valid_data() {
+    assert_eq!(Username::new(" user ").unwrap().get(), "user");
+}
+
+#[test]
+fn construction_reports_failed_check() {
+    assert_matches!(
+        Username::new("   "),
+        Err(prae::ConstructionError {
+            original: UsernameError::Empty,
+            ..
+        })
+    );
+    assert_matches!(
+        Username::new("very long name"),
+        Err(prae::ConstructionError {
+            original: UsernameError::TooLong,
+            ..
+        })
+    );
+    assert_matches!(
+        Username::new("юзер"),
+        Err(prae::ConstructionError {
+            original: UsernameError::NotAscii,
+            ..
+        })
+    );
+}
+
+#[test]
+fn checks_run_in_order() {
+    let err = Username::new("длинное имя").unwrap_err();
+    assert_eq!(err.original, UsernameError::TooLong);
+    assert_eq!(
+        err.original.to_string(),
+        "value doesn't pass the `TooLong` check"
+    );
+}
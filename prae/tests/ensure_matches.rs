@@ -0,0 +1,52 @@
+#[cfg(feature = "regex")]
+mod tests {
+    use prae::Wrapper;
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Extension: String;
+        adjust |e| *e = e.to_lowercase();
+        ensure_matches_any(["^png$", "^jpe?g$"]);
+    }
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Nickname: String;
+        ensure_matches_none(["admin", "^root$"]);
+    }
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Host: String;
+        ensure_matches_any([r"\.com$", r"\.org$"]);
+        ensure_matches_none(["^localhost"]);
+    }
+
+    #[test]
+    fn allow_list_is_checked() {
+        assert_eq!(Extension::new("PNG").unwrap().get(), "png");
+        assert_eq!(Extension::new("jpg").unwrap().get(), "jpg");
+        assert_eq!(Extension::new("jpeg").unwrap().get(), "jpeg");
+        let err = Extension::new("gif").unwrap_err();
+        assert_eq!(
+            err.original,
+            "value doesn't match any of the allowed patterns"
+        );
+        assert_eq!(err.value, "gif");
+    }
+
+    #[test]
+    fn block_list_is_checked() {
+        assert!(Nickname::new("rooted").is_ok());
+        let err = Nickname::new("root").unwrap_err();
+        assert_eq!(err.original, "value matches a forbidden pattern");
+        assert!(Nickname::new("the_admin").is_err());
+    }
+
+    #[test]
+    fn both_lists_are_checked() {
+        assert!(Host::new("example.com").is_ok());
+        assert!(Host::new("example.net").is_err());
+        assert!(Host::new("localhost.org").is_err());
+    }
+}
@@ -0,0 +1,37 @@
+use assert_matches::assert_matches;
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Slug: String;
+    adjust |s| *s = s.trim().to_owned();
+    ensure_msg |s| {
+        if !s.is_empty() && s.chars().all(|c| c.is_ascii_lowercase() || c == '-') {
+            Ok(())
+        } else {
+            Err(format!("'{}' is not a valid slug", s))
+        }
+    };
+}
+
+#[test]
+fn construction_succeeds_with_valid_data() {
+    assert_eq!(Slug::new(" some-slug ").unwrap().get(), "some-slug");
+}
+
+#[test]
+fn construction_error_message_includes_value() {
+    assert_matches!(
+        Slug::new(" Some Slug "),
+        Err(prae::ConstructionError { original, value, .. })
+            if original == "'Some Slug' is not a valid slug" && value == "Some Slug"
+    );
+}
+
+#[test]
+fn mutation_error_message_includes_value() {
+    let mut s = Slug::new("slug").unwrap();
+    let err = s.mutate(|s| s.push('!')).unwrap_err();
+    assert_eq!(err.original, "'slug!' is not a valid slug");
+    assert_eq!(s.get(), "slug");
+}
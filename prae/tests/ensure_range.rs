@@ -0,0 +1,33 @@
+use assert_matches::assert_matches;
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Port: u16;
+    ensure_range(1024, 49151);
+}
+
+const _: () = assert!(Port::in_range(8080));
+const _: () = assert!(!Port::in_range(80));
+
+#[test]
+fn construction_succeeds_within_range() {
+    assert_eq!(*Port::new(1024u16).unwrap().get(), 1024);
+    assert_eq!(*Port::new(49151u16).unwrap().get(), 49151);
+}
+
+#[test]
+fn construction_fails_out_of_range() {
+    assert_matches!(
+        Port::new(1023u16),
+        Err(prae::ConstructionError { original, .. }) if original == "value is out of range"
+    );
+    assert_matches!(Port::new(49152u16), Err(prae::ConstructionError { .. }));
+}
+
+#[test]
+fn in_range_matches_construction() {
+    for port in [0, 1023, 1024, 8080, 49151, 49152, u16::MAX] {
+        assert_eq!(Port::in_range(port), Port::new(port).is_ok());
+    }
+}
@@ -0,0 +1,64 @@
+use prae::{MapOriginalError, Wrapper};
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    ensure |u| !u.is_empty();
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Age: u8;
+    ensure_msg |a: &u8| {
+        if *a > 0 {
+            Ok(())
+        } else {
+            Err("age must be positive".to_owned())
+        }
+    };
+}
+
+#[derive(Debug, PartialEq)]
+enum AppError {
+    Username(&'static str),
+    Age(String),
+}
+
+impl From<&'static str> for AppError {
+    fn from(err: &'static str) -> Self {
+        Self::Username(err)
+    }
+}
+
+impl From<String> for AppError {
+    fn from(err: String) -> Self {
+        Self::Age(err)
+    }
+}
+
+fn register(name: &str, age: u8) -> Result<(Username, Age), AppError> {
+    let name = Username::new(name).err_into::<AppError>()?;
+    let age = Age::new(age).err_into::<AppError>()?;
+    Ok((name, age))
+}
+
+#[test]
+fn construction_error_converts_into_app_error() {
+    assert!(register("user", 20).is_ok());
+    assert_eq!(
+        register("", 20).unwrap_err(),
+        AppError::Username("value is invalid")
+    );
+    assert_eq!(
+        register("user", 0).unwrap_err(),
+        AppError::Age("age must be positive".to_owned())
+    );
+}
+
+#[test]
+fn mutation_error_converts_into_app_error() {
+    let mut u = Username::new("user").unwrap();
+    let res: Result<(), AppError> = u.mutate(|u| u.clear()).err_into();
+    assert_eq!(res.unwrap_err(), AppError::Username("value is invalid"));
+    assert_eq!(u.get(), "user");
+}
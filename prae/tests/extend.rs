@@ -57,3 +57,18 @@ fn double_extended_works() {
         "provided sentence has no ending punctuation mark"
     );
 }
+
+prae::extend! {
+    #[derive(Debug)]
+    ShortText: Text;
+    ensure("provided text is too long") |t| t.len() <= 10;
+}
+
+#[test]
+fn extended_ensure_uses_custom_message() {
+    let e = ShortText::new(" ").unwrap_err();
+    assert_eq!(e.original, "provided text is empty");
+
+    let e = ShortText::new("too long for this type").unwrap_err();
+    assert_eq!(e.original, "provided text is too long");
+}
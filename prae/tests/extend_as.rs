@@ -0,0 +1,74 @@
+use assert_matches::assert_matches;
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Text: String;
+    adjust |t| *t = t.trim().to_owned();
+    ensure |t| !t.is_empty();
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    pub CachedText: Text as (String, usize);
+    project 0;
+    adjust |(t, len)| *len = t.chars().count();
+}
+
+#[derive(Debug)]
+pub struct Counted {
+    text: String,
+    words: usize,
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    pub ShortSentence: Text as Counted;
+    project text;
+    adjust |c| c.words = c.text.split_whitespace().count();
+    ensure("sentence is too long") |c| c.words <= 3;
+}
+
+#[test]
+fn construction_runs_extended_wrapper_on_field() {
+    let t = CachedText::new(("  some text ".to_owned(), 0)).unwrap();
+    assert_eq!(t.get(), &("some text".to_owned(), 9));
+    assert_matches!(
+        CachedText::new(("   ".to_owned(), 42)),
+        Err(prae::ConstructionError {
+            original: "value is invalid",
+            ..
+        })
+    );
+}
+
+#[test]
+fn mutation_keeps_cache_in_sync() {
+    let mut t = CachedText::new(("text".to_owned(), 0)).unwrap();
+    t.mutate(|(t, _)| t.push_str(" and more ")).unwrap();
+    assert_eq!(t.get(), &("text and more".to_owned(), 13));
+    assert!(t.mutate(|(t, _)| t.clear()).is_err());
+    assert_eq!(t.get().1, 13);
+}
+
+#[test]
+fn named_field_projection_works() {
+    let s = ShortSentence::new(Counted {
+        text: " one two three ".to_owned(),
+        words: 0,
+    })
+    .unwrap();
+    assert_eq!(s.get().text, "one two three");
+    assert_eq!(s.get().words, 3);
+    let err = ShortSentence::new(Counted {
+        text: "one two three four".to_owned(),
+        words: 0,
+    })
+    .unwrap_err();
+    assert_eq!(err.original, "sentence is too long");
+    assert!(ShortSentence::new(Counted {
+        text: " ".to_owned(),
+        words: 0,
+    })
+    .is_err());
+}
@@ -0,0 +1,51 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Text: String;
+    adjust |t| *t = t.trim().to_owned();
+    ensure |t: &String| !t.is_empty();
+    plugins: [prae::impl_display];
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    pub Sentence: Text;
+    ensure |s: &String| s.ends_with('.');
+    reapply: [prae::impl_display];
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    pub Title: Text;
+    ensure("title is too long") |s: &String| s.len() <= 16;
+    reapply: [prae::impl_display_truncated(7)];
+    plugins: [prae::impl_deref];
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    pub CachedText: Text as (String, usize);
+    project 0;
+    adjust |(t, len)| *len = t.len();
+    reapply: [prae::impl_deref];
+}
+
+#[test]
+fn reapplied_plugin_works_on_child() {
+    let sentence = Sentence::new("  My sentence. ").unwrap();
+    assert_eq!(sentence.to_string(), "My sentence.");
+}
+
+#[test]
+fn reapply_accepts_arguments_and_goes_with_plugins() {
+    let title = Title::new("A short title").unwrap();
+    assert_eq!(title.to_string(), "A short…");
+    assert_eq!(title.len(), 13);
+}
+
+#[test]
+fn reapply_works_with_widened_inner() {
+    let text = CachedText::new((" text ".to_owned(), 0)).unwrap();
+    assert_eq!(text.1, 4);
+}
@@ -0,0 +1,58 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    adjust prae::adjust::trim;
+    forbid |u| u.contains(' ');
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Slug: String;
+    forbid("no spaces allowed") |s: &String| s.contains(' ');
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub NonEmptyVec<T>: Vec<T>;
+    forbid |v| v.is_empty();
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Batch<T: Clone>: Vec<T>;
+    adjust |v| v.truncate(3);
+    forbid("batch must not be empty") |v| v.is_empty();
+}
+
+#[test]
+fn value_passes_when_closure_returns_false() {
+    assert_eq!(Username::new(" user ").unwrap().get(), "user");
+    assert!(Slug::new("my-post").is_ok());
+    assert!(NonEmptyVec::new(vec![1]).is_ok());
+    assert_eq!(Batch::new(vec![1, 2, 3, 4]).unwrap().get(), &[1, 2, 3]);
+}
+
+#[test]
+fn value_fails_when_closure_returns_true() {
+    assert_eq!(
+        Username::new("user name").unwrap_err().original,
+        "value is invalid"
+    );
+    let mut u = Username::new("user").unwrap();
+    assert!(u.mutate(|u| u.push_str(" name")).is_err());
+    assert!(NonEmptyVec::<u8>::new(vec![]).is_err());
+}
+
+#[test]
+fn custom_message_is_used() {
+    assert_eq!(
+        Slug::new("my post").unwrap_err().original,
+        "no spaces allowed"
+    );
+    assert_eq!(
+        Batch::<u8>::new(vec![]).unwrap_err().original,
+        "batch must not be empty"
+    );
+}
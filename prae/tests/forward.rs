@@ -0,0 +1,36 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Email: String;
+    ensure |e: &String| e.contains('@');
+    forward(as_str -> &str, to_lowercase -> String);
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Tags: Vec<String>;
+    adjust |tags| tags.sort();
+    forward(len -> usize, is_empty -> bool, first -> Option<&String>,);
+}
+
+#[test]
+fn forwarded_method_returns_reference() {
+    let email = Email::new("User@Example.com").unwrap();
+    assert_eq!(email.as_str(), "User@Example.com");
+}
+
+#[test]
+fn forwarded_method_returns_value() {
+    let email = Email::new("User@Example.com").unwrap();
+    assert_eq!(email.to_lowercase(), "user@example.com");
+    assert_eq!(email.get(), "User@Example.com");
+}
+
+#[test]
+fn forward_accepts_trailing_comma() {
+    let tags = Tags::new(vec!["b".to_owned(), "a".to_owned()]).unwrap();
+    assert_eq!(tags.len(), 2);
+    assert!(!tags.is_empty());
+    assert_eq!(tags.first().map(String::as_str), Some("a"));
+}
@@ -0,0 +1,47 @@
+use prae::Wrapper;
+
+// Doesn't implement `Clone`.
+pub struct Config {
+    name: String,
+}
+
+prae::define! {
+    ValidConfig: Config;
+    ensure |c| !c.name.is_empty();
+}
+
+prae::define! {
+    #[derive(Debug)]
+    Username: String;
+}
+
+#[test]
+fn frozen_value_is_readable() {
+    let config = ValidConfig::new(Config {
+        name: "config".to_owned(),
+    })
+    .ok()
+    .unwrap()
+    .freeze();
+    assert_eq!(config.get().name, "config");
+    assert_eq!(config.name, "config");
+}
+
+#[test]
+fn frozen_value_is_cloneable_without_inner_clone() {
+    let config = ValidConfig::new(Config {
+        name: "config".to_owned(),
+    })
+    .ok()
+    .unwrap()
+    .freeze();
+    let clone = config.clone();
+    assert!(std::ptr::eq(config.get(), clone.get()));
+    assert_eq!(clone.name, "config");
+}
+
+#[test]
+fn frozen_value_formats_correctly() {
+    let un = Username::new("user").unwrap().freeze();
+    assert_eq!(format!("{:?}", un), "Frozen(\"user\")");
+}
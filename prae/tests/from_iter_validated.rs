@@ -0,0 +1,36 @@
+use assert_matches::assert_matches;
+use prae::Wrapper;
+use std::collections::HashSet;
+
+prae::define! {
+    #[derive(Debug)]
+    Numbers: Vec<u64>;
+    ensure |n| !n.is_empty();
+}
+
+prae::define! {
+    #[derive(Debug)]
+    Tags: HashSet<String>;
+    ensure |t| t.len() <= 2;
+}
+
+#[test]
+fn construction_from_iter_succeeds_for_valid_data() {
+    let nums = Numbers::from_iter_validated((1..=3).map(|n| n * 2)).unwrap();
+    assert_eq!(nums.get(), &[2, 4, 6]);
+
+    let tags = Tags::from_iter_validated(["a", "b", "a"].map(String::from)).unwrap();
+    assert_eq!(tags.get().len(), 2);
+}
+
+#[test]
+fn construction_from_iter_fails_for_invalid_data() {
+    assert_matches!(
+        Numbers::from_iter_validated(std::iter::empty()),
+        Err(prae::ConstructionError { .. })
+    );
+    assert_matches!(
+        Tags::from_iter_validated(["a", "b", "c"].map(String::from)),
+        Err(prae::ConstructionError { .. })
+    );
+}
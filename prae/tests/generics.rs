@@ -0,0 +1,139 @@
+use assert_matches::assert_matches;
+use prae::Wrapper;
+use std::fmt::Display;
+
+prae::define! {
+    #[derive(Debug)]
+    NonEmptyVec<T>: Vec<T>;
+    ensure |v| !v.is_empty();
+}
+
+prae::define! {
+    #[derive(Debug)]
+    SortedVec<T: Clone + Ord>: Vec<T>;
+    adjust |v| v.sort();
+    ensure("vec must not be empty") |v| !v.is_empty();
+}
+
+prae::define! {
+    #[derive(Debug)]
+    Pair<A, B>: (A, B);
+}
+
+prae::define! {
+    #[derive(Debug)]
+    Shared<T>: Vec<T> where T: Clone + Send, Vec<T>: PartialEq;
+    ensure |v| !v.is_empty();
+}
+
+prae::define! {
+    #[derive(Debug)]
+    Labeled<K: Ord>: (K, String) where String: Clone,;
+    ensure("label must not be empty") |(_, label)| !label.is_empty();
+}
+
+prae::define! {
+    #[derive(Debug)]
+    Port: u16 where u16: Display + Default;
+    ensure |p| *p != 0;
+    plugins: [
+        prae::impl_display,
+    ];
+}
+
+prae::define! {
+    Tags<T: std::fmt::Debug + Clone>: Vec<T>;
+    ensure |v| !v.is_empty();
+    plugins: [
+        prae::impl_deref,
+        prae::impl_index,
+        prae::impl_debug,
+        prae::impl_hash_eq_by(|v| v.len()),
+    ];
+}
+
+prae::define! {
+    #[derive(Debug)]
+    Lines<T>: Vec<T> where T: std::fmt::Debug;
+    plugins: [
+        prae::impl_default,
+        prae::impl_debug_pretty,
+    ];
+}
+
+#[test]
+fn construction_succeeds_for_any_type() {
+    assert_eq!(NonEmptyVec::new(vec![1, 2]).unwrap().get(), &vec![1, 2]);
+    assert_eq!(NonEmptyVec::new(vec!["a"]).unwrap().get(), &vec!["a"]);
+}
+
+#[test]
+fn construction_fails_for_invalid_data() {
+    assert_matches!(
+        NonEmptyVec::<u8>::new(vec![]),
+        Err(prae::ConstructionError { value, .. }) if value.is_empty()
+    );
+}
+
+#[test]
+fn bounds_are_propagated() {
+    let mut v = SortedVec::new(vec![3, 1, 2]).unwrap();
+    assert_eq!(v.get(), &vec![1, 2, 3]);
+    v.mutate(|v| v.push(0)).unwrap();
+    assert_eq!(v.get(), &vec![0, 1, 2, 3]);
+    assert_eq!(
+        SortedVec::<u8>::new(vec![]).unwrap_err().original,
+        "vec must not be empty"
+    );
+}
+
+#[test]
+fn multiple_type_parameters_work() {
+    let p = Pair::new((1, "one")).unwrap();
+    let inner: (i32, &str) = p.into();
+    assert_eq!(inner, (1, "one"));
+    let p: Pair<i32, &str> = (2, "two").try_into().unwrap();
+    assert_eq!(p.as_ref(), &(2, "two"));
+}
+
+#[test]
+fn where_clauses_are_propagated() {
+    fn assert_send<T: Send>(_: &T) {}
+
+    let v = Shared::new(vec![1, 2]).unwrap();
+    assert_send(&v);
+    assert_eq!(v.get(), &vec![1, 2]);
+    let inner: Vec<i32> = v.into();
+    assert_eq!(inner, vec![1, 2]);
+    assert!(Shared::<u8>::new(vec![]).is_err());
+
+    let l = Labeled::new((1, "one".to_owned())).unwrap();
+    assert_eq!(l.as_ref().1, "one");
+    assert_eq!(
+        Labeled::new((1, String::new())).unwrap_err().original,
+        "label must not be empty"
+    );
+}
+
+#[test]
+fn where_clauses_work_with_plugins() {
+    let p = Port::new(8080u16).unwrap();
+    assert_eq!(p.to_string(), "8080");
+    assert!(Port::new(u16::default()).is_err());
+}
+
+#[test]
+fn plugins_work_with_generic_wrappers() {
+    let tags = Tags::new(vec!["a", "b"]).unwrap();
+    assert_eq!(tags.len(), 2);
+    assert_eq!(tags[1], "b");
+    assert_eq!(format!("{:?}", tags), "Tags([\"a\", \"b\"])");
+    assert!(tags == Tags::new(vec!["c", "d"]).unwrap());
+}
+
+#[test]
+fn where_clauses_are_passed_to_plugins() {
+    let lines = Lines::<u8>::default();
+    assert!(lines.get().is_empty());
+    assert_eq!(Lines::new(vec![1]).unwrap().debug_pretty(), "[\n    1,\n]");
+}
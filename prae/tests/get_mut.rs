@@ -0,0 +1,33 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    Numbers: Vec<u64>;
+    adjust |n| n.sort_unstable();
+    ensure |n| !n.is_empty();
+}
+
+#[test]
+fn get_mut_mutates_in_place() {
+    let mut nums = Numbers::new([1, 2]).unwrap();
+    nums.get_mut().push(3);
+    assert_eq!(nums.get(), &[1, 2, 3]);
+}
+
+#[test]
+fn get_mut_adjusts_on_drop() {
+    let mut nums = Numbers::new([2, 3]).unwrap();
+    {
+        let mut guard = nums.get_mut();
+        guard.push(1);
+        assert_eq!(*guard, [2, 3, 1]);
+    }
+    assert_eq!(nums.get(), &[1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "value of type Numbers is invalid after mutation through `get_mut`")]
+fn get_mut_panics_for_invalid_data() {
+    let mut nums = Numbers::new([1]).unwrap();
+    nums.get_mut().clear();
+}
@@ -0,0 +1,96 @@
+#[cfg(feature = "arbitrary")]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+    use prae::Wrapper;
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Even: u8;
+        ensure |n| n % 2 == 0;
+        plugins: [
+            prae::impl_arbitrary(16),
+        ];
+    }
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Never: u8;
+        ensure |_| false;
+        plugins: [
+            prae::impl_arbitrary,
+        ];
+    }
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Slug: String;
+        ensure |s: &String| s.len() >= 3 && s.bytes().all(|b| b.is_ascii_lowercase());
+        plugins: [
+            prae::impl_arbitrary(with = |u| {
+                let len = u.int_in_range(3..=10)?;
+                (0..len).map(|_| Ok(char::from(u.int_in_range(b'a'..=b'z')?))).collect()
+            }),
+        ];
+    }
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Broken: u8;
+        ensure |n| *n > 100;
+        plugins: [
+            prae::impl_arbitrary(with = |_| Ok(0)),
+        ];
+    }
+
+    #[test]
+    fn generated_values_are_valid() {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut u = Unstructured::new(&data);
+        for _ in 0..64 {
+            let e = Even::arbitrary(&mut u).unwrap();
+            assert_eq!(e.get() % 2, 0);
+        }
+    }
+
+    #[test]
+    fn invalid_values_are_rejected() {
+        let mut u = Unstructured::new(&[1, 2, 3]);
+        assert_eq!(
+            Never::arbitrary(&mut u).unwrap_err(),
+            arbitrary::Error::IncorrectFormat
+        );
+    }
+
+    #[test]
+    fn retries_are_bounded() {
+        let mut u = Unstructured::new(&[]);
+        prae::define! {
+            #[derive(Debug)]
+            pub NeverRetried: u8;
+            ensure |_| false;
+            plugins: [
+                prae::impl_arbitrary(1000),
+            ];
+        }
+        assert!(NeverRetried::arbitrary(&mut u).is_err());
+    }
+
+    #[test]
+    fn custom_generator_produces_valid_values() {
+        let data: Vec<u8> = (0..=255).cycle().take(4096).collect();
+        let mut u = Unstructured::new(&data);
+        for _ in 0..64 {
+            let s = Slug::arbitrary(&mut u).unwrap();
+            assert!(Slug::is_valid(s.get()));
+        }
+    }
+
+    #[test]
+    fn broken_custom_generator_is_rejected() {
+        let mut u = Unstructured::new(&[1, 2, 3]);
+        assert_eq!(
+            Broken::arbitrary(&mut u).unwrap_err(),
+            arbitrary::Error::IncorrectFormat
+        );
+    }
+}
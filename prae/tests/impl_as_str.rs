@@ -0,0 +1,37 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    adjust |u| *u = u.trim().to_owned();
+    ensure |u: &String| !u.is_empty();
+    plugins: [
+        prae::impl_as_str,
+        prae::impl_into_string,
+    ];
+}
+
+fn len(s: impl AsRef<str>) -> usize {
+    s.as_ref().len()
+}
+
+#[test]
+fn as_str_works() {
+    let u = Username::new("  user  ").unwrap();
+    assert_eq!(u.as_str(), "user");
+    assert!(u.as_str().starts_with("us"));
+}
+
+#[test]
+fn as_ref_str_works() {
+    let u = Username::new("user").unwrap();
+    assert_eq!(len(&u), 4);
+    assert_eq!(len(u), 4);
+}
+
+#[test]
+fn into_string_works() {
+    let u = Username::new("user").unwrap();
+    let s: String = u.into_string();
+    assert_eq!(s, "user");
+}
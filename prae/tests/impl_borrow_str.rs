@@ -0,0 +1,41 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub Username: String;
+    adjust |u| *u = u.to_lowercase();
+    ensure |u: &String| !u.is_empty();
+    plugins: [
+        prae::impl_borrow_str,
+    ];
+}
+
+#[test]
+fn hash_map_can_be_queried_by_str() {
+    let mut ages = HashMap::new();
+    ages.insert(Username::new("Alice").unwrap(), 30);
+    ages.insert(Username::new("bob").unwrap(), 42);
+    assert_eq!(ages.get("alice"), Some(&30));
+    assert_eq!(ages.get("bob"), Some(&42));
+    assert!(ages.contains_key("bob"));
+    assert_eq!(ages.remove("alice"), Some(30));
+    assert_eq!(ages.len(), 1);
+}
+
+#[test]
+fn sets_can_be_queried_by_str() {
+    let hash: HashSet<_> = [Username::new("bob").unwrap()].into();
+    assert!(hash.contains("bob"));
+    let tree: BTreeSet<_> = [Username::new("bob").unwrap()].into();
+    assert!(tree.contains("bob"));
+}
+
+#[test]
+fn query_is_not_adjusted() {
+    let mut ages = HashMap::new();
+    ages.insert(Username::new("Bob").unwrap(), 42);
+    assert_eq!(ages.get("Bob"), None);
+    assert_eq!(ages.get("bob"), Some(&42));
+}
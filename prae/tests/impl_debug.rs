@@ -0,0 +1,50 @@
+use prae::Wrapper;
+
+prae::define! {
+    Username: String;
+    plugins: [
+        prae::impl_debug,
+    ];
+}
+
+#[derive(Debug)]
+struct User {
+    name: &'static str,
+}
+
+prae::define! {
+    ValidUser: User;
+    ensure |u| !u.name.is_empty();
+    plugins: [
+        prae::impl_debug,
+    ];
+}
+
+#[test]
+fn debug_shows_wrapper_name() {
+    let un = Username::new("bob").unwrap();
+    assert_eq!(format!("{:?}", un), "Username(\"bob\")");
+}
+
+#[test]
+fn debug_supports_alternate_form() {
+    let u = ValidUser::new(User { name: "bob" }).unwrap();
+    assert_eq!(
+        format!("{:#?}", u),
+        "ValidUser(\n    User {\n        name: \"bob\",\n    },\n)"
+    );
+}
+
+#[test]
+fn debug_works_in_errors() {
+    let err = ValidUser::new(User { name: "" }).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to construct type ValidUser from value User { name: \"\" }: value is invalid"
+    );
+    assert_eq!(
+        format!("{:?}", err),
+        "ConstructionError { value: User { name: \"\" }, original: \"value is invalid\", \
+        rejection: Rejection { phase: Validate, wrapper: \"ValidUser\" } }"
+    );
+}
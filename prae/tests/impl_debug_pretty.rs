@@ -0,0 +1,28 @@
+use prae::Wrapper;
+
+#[derive(Debug)]
+struct User {
+    name: String,
+    age: u8,
+}
+
+prae::define! {
+    ValidUser: User;
+    ensure |u| !u.name.is_empty() && u.age > 0;
+    plugins: [
+        prae::impl_debug_pretty,
+    ];
+}
+
+#[test]
+fn debug_pretty_works() {
+    let user = ValidUser::new(User {
+        name: "user".to_owned(),
+        age: 20,
+    })
+    .unwrap_or_else(|_| panic!("user is invalid"));
+    assert_eq!(
+        user.debug_pretty(),
+        "User {\n    name: \"user\",\n    age: 20,\n}"
+    );
+}
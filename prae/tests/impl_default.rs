@@ -0,0 +1,31 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    Comment: String;
+    adjust |c| *c = c.trim().to_owned();
+    plugins: [
+        prae::impl_default,
+    ];
+}
+
+prae::define! {
+    #[derive(Debug)]
+    Port: u16;
+    ensure |p| *p >= 1024;
+    plugins: [
+        prae::impl_default,
+    ];
+}
+
+#[test]
+fn default_works_for_valid_default() {
+    let c = Comment::default();
+    assert_eq!(c.get(), "");
+}
+
+#[test]
+#[should_panic(expected = "default value of type Port doesn't pass the validation")]
+fn default_panics_for_invalid_default() {
+    let _ = Port::default();
+}
@@ -0,0 +1,62 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    Comment: String;
+    plugins: [
+        prae::impl_display_truncated(10),
+    ];
+}
+
+prae::define! {
+    #[derive(Debug)]
+    Counter: u64;
+    plugins: [
+        prae::impl_display_truncated(3),
+    ];
+}
+
+#[test]
+fn short_value_is_not_truncated() {
+    let c = Comment::new("short").unwrap();
+    assert_eq!(c.to_string(), "short");
+    let c = Comment::new("exactly 10").unwrap();
+    assert_eq!(c.to_string(), "exactly 10");
+}
+
+#[test]
+fn long_value_is_truncated() {
+    let c = Comment::new("x".repeat(1000)).unwrap();
+    assert_eq!(c.to_string(), format!("{}…", "x".repeat(10)));
+    assert_eq!(c.get().len(), 1000);
+}
+
+#[test]
+fn truncation_counts_characters() {
+    let c = Comment::new("привет, мир!").unwrap();
+    assert_eq!(c.to_string(), "привет, ми…");
+}
+
+#[test]
+fn non_string_values_are_truncated() {
+    assert_eq!(Counter::new(123u64).unwrap().to_string(), "123");
+    assert_eq!(Counter::new(123456u64).unwrap().to_string(), "123…");
+}
+
+prae::define! {
+    #[derive(Debug)]
+    Payload: Vec<u8>;
+    plugins: [
+        prae::impl_display_truncated(hex, 2),
+    ];
+}
+
+#[test]
+fn byte_values_are_truncated_as_hex() {
+    assert_eq!(Payload::new(vec![0x0a]).unwrap().to_string(), "0a");
+    assert_eq!(Payload::new(vec![0x0a, 0xff]).unwrap().to_string(), "0aff");
+    assert_eq!(
+        Payload::new(vec![0x0a, 0xff, 0x10]).unwrap().to_string(),
+        "0aff…"
+    );
+}
@@ -0,0 +1,37 @@
+use assert_matches::assert_matches;
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    PositiveInt: i64;
+    ensure |n| *n > 0;
+    plugins: [
+        prae::impl_from_str,
+    ];
+}
+
+#[test]
+fn parsing_succeeds_for_valid_data() {
+    let n: PositiveInt = "42".parse().unwrap();
+    assert_eq!(*n.get(), 42);
+}
+
+#[test]
+fn parsing_fails_for_unparsable_string() {
+    let err = "abc".parse::<PositiveInt>().unwrap_err();
+    assert_matches!(err, prae::FromStrError::Parse(_));
+    assert_eq!(
+        err.to_string(),
+        "failed to parse type PositiveInt: invalid digit found in string"
+    );
+}
+
+#[test]
+fn parsing_fails_for_invalid_data() {
+    let err = "-1".parse::<PositiveInt>().unwrap_err();
+    assert_matches!(err, prae::FromStrError::Construct(_));
+    assert_eq!(
+        err.to_string(),
+        "failed to construct type PositiveInt from value -1: value is invalid"
+    );
+}
@@ -0,0 +1,31 @@
+use prae::Wrapper;
+use std::collections::HashMap;
+
+prae::define! {
+    #[derive(Debug)]
+    Email: String;
+    adjust |e| *e = e.trim().to_owned();
+    ensure |e| e.contains('@');
+    plugins: [
+        prae::impl_hash_eq_by(|e| e.to_lowercase()),
+    ];
+}
+
+#[test]
+fn equality_uses_key() {
+    let a = Email::new("User@Example.com ").unwrap();
+    let b = Email::new("  user@example.com").unwrap();
+    let c = Email::new("other@example.com").unwrap();
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert_eq!(a.get(), "User@Example.com");
+}
+
+#[test]
+fn hash_uses_key() {
+    let mut map = HashMap::new();
+    map.insert(Email::new("User@Example.com").unwrap(), 1);
+    map.insert(Email::new("user@example.com").unwrap(), 2);
+    assert_eq!(map.len(), 1);
+    assert_eq!(map[&Email::new("USER@EXAMPLE.COM").unwrap()], 2);
+}
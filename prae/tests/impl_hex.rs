@@ -0,0 +1,38 @@
+#[cfg(feature = "hex")]
+mod tests {
+    use assert_matches::assert_matches;
+    use prae::Wrapper;
+
+    prae::define! {
+        #[derive(Debug)]
+        Token: Vec<u8>;
+        ensure |t| t.len() == 4;
+        plugins: [
+            prae::impl_hex,
+        ];
+    }
+
+    #[test]
+    fn hex_round_trip_works() {
+        let t = Token::new([0xde, 0xad, 0xbe, 0xef]).unwrap();
+        assert_eq!(t.to_hex(), "deadbeef");
+        let t = Token::from_hex(&t.to_hex()).unwrap();
+        assert_eq!(t.get(), &[0xde, 0xad, 0xbe, 0xef]);
+    }
+
+    #[test]
+    fn from_hex_fails_for_invalid_hex() {
+        let err = Token::from_hex("deadbeeg").unwrap_err();
+        assert_matches!(err, prae::FromHexError::Decode(_));
+        assert_eq!(
+            err.to_string(),
+            "failed to decode hex for type Token: Invalid character 'g' at position 7"
+        );
+    }
+
+    #[test]
+    fn from_hex_fails_for_invalid_data() {
+        let err = Token::from_hex("dead").unwrap_err();
+        assert_matches!(err, prae::FromHexError::Construct(_));
+    }
+}
@@ -0,0 +1,67 @@
+use prae::Wrapper;
+
+fn parse_version(v: &str) -> Vec<u32> {
+    v.split('.').map(|n| n.parse().unwrap()).collect()
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub SemVer: String;
+    ensure |v: &String| v.split('.').all(|n| n.parse::<u32>().is_ok());
+    plugins: [
+        prae::impl_hash_eq_by(|v| parse_version(v)),
+        prae::impl_ord_by(|v| parse_version(v)),
+    ];
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    label: &'static str,
+    value: f64,
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Valid: Measurement;
+    ensure |m: &Measurement| m.value.is_finite() && !m.label.is_empty();
+    plugins: [
+        prae::impl_partial_ord_by(|m| m.value),
+    ];
+}
+
+// Compare by the same key as the ordering, so that `a <= b && a >= b`
+// implies `a == b`.
+impl PartialEq for Valid {
+    fn eq(&self, other: &Self) -> bool {
+        self.get().value == other.get().value
+    }
+}
+
+#[test]
+fn ord_uses_projection() {
+    let a = SemVer::new("1.9.0").unwrap();
+    let b = SemVer::new("1.10.0").unwrap();
+    assert!(a < b);
+    assert!(a.get() > b.get());
+    assert_eq!(a.cmp(&b), std::cmp::Ordering::Less);
+}
+
+#[test]
+fn sorting_uses_projection() {
+    let mut versions: Vec<_> = ["2.0.0", "1.10.0", "1.9.3"]
+        .into_iter()
+        .map(|v| SemVer::new(v).unwrap())
+        .collect();
+    versions.sort();
+    let versions: Vec<_> = versions.iter().map(|v| v.get().as_str()).collect();
+    assert_eq!(versions, ["1.9.3", "1.10.0", "2.0.0"]);
+}
+
+#[test]
+fn partial_ord_uses_projection() {
+    let m = |label, value| Valid::new(Measurement { label, value }).unwrap();
+    assert!(m("z", 1.0) < m("a", 2.0));
+    assert!(m("a", 2.0) > m("z", 1.0));
+    assert!(m("a", 1.0) <= m("z", 1.0));
+    assert_eq!(m("a", 1.0), m("z", 1.0));
+}
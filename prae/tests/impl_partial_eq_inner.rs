@@ -0,0 +1,45 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    adjust |u| *u = u.trim().to_owned();
+    plugins: [
+        prae::impl_partial_eq_inner(&str, str),
+    ];
+}
+
+prae::define! {
+    #[derive(Debug, PartialEq)]
+    pub Port: u16;
+    plugins: [
+        prae::impl_partial_eq_inner,
+    ];
+}
+
+#[test]
+fn compares_with_inner_type() {
+    let u = Username::new(" bob ").unwrap();
+    let (bob, alice) = ("bob".to_owned(), "alice".to_owned());
+    assert!(u == bob);
+    assert!(bob == u);
+    assert!(u != alice);
+}
+
+#[test]
+fn compares_with_additional_types() {
+    let u = Username::new("bob").unwrap();
+    assert!(u == "bob");
+    assert!("bob" == u);
+    assert!(u == *"bob");
+    assert!(*"bob" == u);
+    assert!(u != "alice");
+}
+
+#[test]
+fn works_alongside_derived_partial_eq() {
+    let p = Port::new(8080u16).unwrap();
+    assert_eq!(p, 8080);
+    assert_eq!(8080, p);
+    assert_eq!(p, Port::new(8080u16).unwrap());
+}
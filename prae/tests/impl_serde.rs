@@ -40,6 +40,34 @@ mod tests {
         assert_eq!(err.to_string(), "value is invalid at line 4 column 9");
     }
 
+    prae::define! {
+        #[derive(Debug)]
+        Bio: String;
+        adjust |b| *b = b.trim().to_owned();
+        skippable |b| b.is_empty();
+        plugins: [
+            prae::impl_serde
+        ];
+    }
+
+    #[derive(Debug, Serialize)]
+    struct Profile {
+        #[serde(skip_serializing_if = "Bio::is_skippable")]
+        bio: Bio,
+    }
+
+    #[test]
+    fn serialization_skips_skippable_value() {
+        let p = Profile {
+            bio: Bio::new("   ").unwrap(),
+        };
+        assert_eq!(serde_json::to_string(&p).unwrap(), "{}");
+        let p = Profile {
+            bio: Bio::new(" some bio ").unwrap(),
+        };
+        assert_eq!(serde_json::to_string(&p).unwrap(), r#"{"bio":"some bio"}"#);
+    }
+
     #[test]
     fn serialization_succeeds() {
         let u = User {
@@ -48,4 +76,120 @@ mod tests {
         let json = serde_json::to_string(&u).unwrap();
         assert_eq!(r#"{"name":"some name"}"#, json)
     }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(tag = "type", rename_all = "snake_case")]
+    enum EventKind {
+        Click { x: i32, y: i32 },
+        Key { code: String },
+    }
+
+    prae::define! {
+        #[derive(Debug)]
+        Event: EventKind;
+        ensure |e| match e {
+            EventKind::Click { x, y } => *x >= 0 && *y >= 0,
+            EventKind::Key { code } => !code.is_empty(),
+        };
+        plugins: [
+            prae::impl_serde
+        ];
+    }
+
+    #[test]
+    fn internally_tagged_enum_roundtrips() {
+        let e = Event::new(EventKind::Click { x: 1, y: 2 }).unwrap();
+        let json = serde_json::to_string(&e).unwrap();
+        assert_eq!(json, r#"{"type":"click","x":1,"y":2}"#);
+        let e: Event = serde_json::from_str(&json).unwrap();
+        assert_eq!(e.get(), &EventKind::Click { x: 1, y: 2 });
+
+        let e: Event = serde_json::from_str(r#"{"type":"key","code":"Enter"}"#).unwrap();
+        assert_eq!(
+            e.get(),
+            &EventKind::Key {
+                code: "Enter".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn internally_tagged_enum_is_validated() {
+        let err = serde_json::from_str::<Event>(r#"{"type":"key","code":""}"#).unwrap_err();
+        assert_eq!(err.to_string(), "value is invalid");
+        assert!(serde_json::from_str::<Event>(r#"{"type":"scroll"}"#).is_err());
+    }
+
+    prae::define! {
+        #[derive(Debug)]
+        Nickname: String;
+        ensure |n: &String| !n.is_empty();
+        plugins: [
+            prae::impl_serde,
+        ];
+    }
+
+    mod lowercase {
+        use super::Nickname;
+        use prae::Wrapper;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(n: &Nickname, s: S) -> Result<S::Ok, S::Error> {
+            s.serialize_str(&n.get().to_lowercase())
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Nickname, D::Error> {
+            let value = String::deserialize(d)?.to_lowercase();
+            Nickname::new(value).map_err(serde::de::Error::custom)
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Account {
+        #[serde(rename = "login")]
+        name: Username,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        nickname: Option<Nickname>,
+        #[serde(with = "lowercase")]
+        alias: Nickname,
+    }
+
+    #[test]
+    fn field_attributes_of_containing_struct_are_respected() {
+        let json = r#"{"login":" bob ","alias":"BOB"}"#;
+        let a: Account = serde_json::from_str(json).unwrap();
+        assert_eq!(a.name.get(), "bob");
+        assert!(a.nickname.is_none());
+        assert_eq!(a.alias.get(), "bob");
+        assert_eq!(
+            serde_json::to_string(&a).unwrap(),
+            r#"{"login":"bob","alias":"bob"}"#,
+        );
+    }
+
+    #[test]
+    fn field_attributes_keep_validation() {
+        let json = r#"{"login":" ","alias":"bob"}"#;
+        assert!(serde_json::from_str::<Account>(json).is_err());
+        let json = r#"{"login":"bob","nickname":"","alias":"bob"}"#;
+        assert!(serde_json::from_str::<Account>(json).is_err());
+        let json = r#"{"login":"bob","alias":""}"#;
+        assert!(serde_json::from_str::<Account>(json).is_err());
+    }
+
+    prae::define! {
+        #[derive(Debug)]
+        Batch<T: serde::Serialize>: Vec<T>;
+        ensure |b| !b.is_empty();
+        plugins: [
+            prae::impl_serde,
+        ];
+    }
+
+    #[test]
+    fn generic_wrappers_are_validated() {
+        let b: Batch<u8> = serde_json::from_str("[1, 2]").unwrap();
+        assert_eq!(serde_json::to_string(&b).unwrap(), "[1,2]");
+        assert!(serde_json::from_str::<Batch<u8>>("[]").is_err());
+    }
 }
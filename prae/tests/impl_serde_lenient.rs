@@ -0,0 +1,66 @@
+#[cfg(feature = "serde_json")]
+mod tests {
+    use prae::Wrapper;
+    use serde::{Deserialize, Serialize};
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Port: u16;
+        ensure |p: &u16| *p >= 1024;
+        plugins: [
+            prae::impl_serde_lenient,
+        ];
+    }
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Code: String;
+        adjust |c| *c = c.trim().to_owned();
+        ensure |c: &String| !c.is_empty();
+        plugins: [
+            prae::impl_serde_lenient,
+        ];
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Config {
+        port: Port,
+    }
+
+    #[test]
+    fn inner_value_is_accepted() {
+        let c: Config = serde_json::from_str(r#"{"port":8080}"#).unwrap();
+        assert_eq!(c.port.get(), &8080);
+    }
+
+    #[test]
+    fn string_value_is_parsed() {
+        let c: Config = serde_json::from_str(r#"{"port":"8080"}"#).unwrap();
+        assert_eq!(c.port.get(), &8080);
+        assert_eq!(serde_json::to_string(&c).unwrap(), r#"{"port":8080}"#);
+    }
+
+    #[test]
+    fn both_forms_are_validated() {
+        let err = serde_json::from_str::<Port>("80").unwrap_err();
+        assert_eq!(err.to_string(), "value is invalid");
+        let err = serde_json::from_str::<Port>("\"80\"").unwrap_err();
+        assert_eq!(err.to_string(), "value is invalid");
+    }
+
+    #[test]
+    fn invalid_input_reports_parse_error() {
+        let err = serde_json::from_str::<Port>("\"http\"").unwrap_err();
+        assert_eq!(err.to_string(), "invalid digit found in string");
+        assert!(serde_json::from_str::<Port>("true").is_err());
+        assert!(serde_json::from_str::<Port>("70000").is_err());
+    }
+
+    #[test]
+    fn string_inner_behaves_like_impl_serde() {
+        let c: Code = serde_json::from_str("\" abc \"").unwrap();
+        assert_eq!(c.get(), "abc");
+        assert!(serde_json::from_str::<Code>("\"  \"").is_err());
+        assert!(serde_json::from_str::<Code>("42").is_err());
+    }
+}
@@ -0,0 +1,130 @@
+#[cfg(feature = "serde")]
+mod tests {
+    use prae::Wrapper;
+    use serde::ser::{self, Impossible, Serialize};
+    use std::fmt;
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Username: String;
+        adjust |u| *u = u.trim().to_owned();
+        ensure |u: &String| !u.is_empty();
+        plugins: [
+            prae::impl_serde(newtype),
+        ];
+    }
+
+    #[derive(Debug)]
+    struct Unsupported;
+
+    impl fmt::Display for Unsupported {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "unsupported")
+        }
+    }
+
+    impl std::error::Error for Unsupported {}
+
+    impl ser::Error for Unsupported {
+        fn custom<T: fmt::Display>(_: T) -> Self {
+            Unsupported
+        }
+    }
+
+    /// Serializer that renders strings and newtype structs like `Name("...")`.
+    struct Render;
+
+    macro_rules! unsupported {
+        ($($method:ident($($ty:ty),*) -> $ret:ty;)*) => {
+            $(fn $method(self, $(_: $ty),*) -> Result<$ret, Unsupported> {
+                Err(Unsupported)
+            })*
+        };
+    }
+
+    impl ser::Serializer for Render {
+        type Ok = String;
+        type Error = Unsupported;
+        type SerializeSeq = Impossible<String, Unsupported>;
+        type SerializeTuple = Impossible<String, Unsupported>;
+        type SerializeTupleStruct = Impossible<String, Unsupported>;
+        type SerializeTupleVariant = Impossible<String, Unsupported>;
+        type SerializeMap = Impossible<String, Unsupported>;
+        type SerializeStruct = Impossible<String, Unsupported>;
+        type SerializeStructVariant = Impossible<String, Unsupported>;
+
+        fn serialize_str(self, v: &str) -> Result<String, Unsupported> {
+            Ok(format!("{:?}", v))
+        }
+
+        fn serialize_newtype_struct<T: ?Sized + Serialize>(
+            self,
+            name: &'static str,
+            value: &T,
+        ) -> Result<String, Unsupported> {
+            Ok(format!("{}({})", name, value.serialize(Render)?))
+        }
+
+        fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<String, Unsupported> {
+            Err(Unsupported)
+        }
+
+        fn serialize_newtype_variant<T: ?Sized + Serialize>(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: &T,
+        ) -> Result<String, Unsupported> {
+            Err(Unsupported)
+        }
+
+        unsupported! {
+            serialize_bool(bool) -> String;
+            serialize_i8(i8) -> String;
+            serialize_i16(i16) -> String;
+            serialize_i32(i32) -> String;
+            serialize_i64(i64) -> String;
+            serialize_u8(u8) -> String;
+            serialize_u16(u16) -> String;
+            serialize_u32(u32) -> String;
+            serialize_u64(u64) -> String;
+            serialize_f32(f32) -> String;
+            serialize_f64(f64) -> String;
+            serialize_char(char) -> String;
+            serialize_bytes(&[u8]) -> String;
+            serialize_none() -> String;
+            serialize_unit() -> String;
+            serialize_unit_struct(&'static str) -> String;
+            serialize_unit_variant(&'static str, u32, &'static str) -> String;
+            serialize_seq(Option<usize>) -> Self::SerializeSeq;
+            serialize_tuple(usize) -> Self::SerializeTuple;
+            serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct;
+            serialize_tuple_variant(&'static str, u32, &'static str, usize) -> Self::SerializeTupleVariant;
+            serialize_map(Option<usize>) -> Self::SerializeMap;
+            serialize_struct(&'static str, usize) -> Self::SerializeStruct;
+            serialize_struct_variant(&'static str, u32, &'static str, usize) -> Self::SerializeStructVariant;
+        }
+    }
+
+    #[test]
+    fn serializes_as_newtype_struct() {
+        let u = Username::new("user").unwrap();
+        assert_eq!(u.serialize(Render).unwrap(), "Username(\"user\")");
+    }
+
+    #[test]
+    fn roundtrips_through_transparent_formats() {
+        let u = Username::new(" user ").unwrap();
+        let json = serde_json::to_string(&u).unwrap();
+        assert_eq!(json, "\"user\"");
+        let u: Username = serde_json::from_str(&json).unwrap();
+        assert_eq!(u.get(), "user");
+    }
+
+    #[test]
+    fn deserialization_fails_with_invalid_data() {
+        let err = serde_json::from_str::<Username>("\"  \"").unwrap_err();
+        assert_eq!(err.to_string(), "value is invalid");
+    }
+}
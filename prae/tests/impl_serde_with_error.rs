@@ -0,0 +1,77 @@
+#[cfg(feature = "serde")]
+mod tests {
+    use prae::Wrapper;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum AgeError {
+        TooYoung,
+        TooOld,
+    }
+
+    impl std::fmt::Display for AgeError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Self::TooYoung => write!(f, "age is too young"),
+                Self::TooOld => write!(f, "age is too old"),
+            }
+        }
+    }
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Age: u8;
+        validate(AgeError) |a| match *a {
+            0..=17 => Err(AgeError::TooYoung),
+            18..=120 => Ok(()),
+            _ => Err(AgeError::TooOld),
+        };
+        plugins: [
+            prae::impl_serde_with_error,
+        ];
+    }
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Password: String;
+        ensure |p: &String| p.len() >= 8;
+        redact_value;
+        plugins: [
+            prae::impl_serde_with_error,
+        ];
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct User {
+        age: Age,
+        password: Password,
+    }
+
+    #[test]
+    fn valid_data_roundtrips() {
+        let json = r#"{"age":30,"password":"correct horse"}"#;
+        let u: User = serde_json::from_str(json).unwrap();
+        assert_eq!(*u.age.get(), 30);
+        assert_eq!(serde_json::to_string(&u).unwrap(), json);
+    }
+
+    #[test]
+    fn deserialization_error_contains_construction_error() {
+        let err = serde_json::from_str::<Age>("150").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "failed to construct type Age from value 150: age is too old"
+        );
+    }
+
+    #[test]
+    fn deserialization_error_respects_redaction() {
+        let json = r#"{"age":30,"password":"hunter2"}"#;
+        let err = serde_json::from_str::<User>(json).unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.starts_with(
+            "failed to construct type Password from value <redacted>: value is invalid"
+        ));
+        assert!(!msg.contains("hunter2"));
+    }
+}
@@ -0,0 +1,44 @@
+#[cfg(feature = "ts-rs")]
+mod tests {
+    use ts_rs::{Config, TS};
+
+    prae::define! {
+        #[derive(Debug)]
+        Username: String;
+        ensure |u| !u.is_empty();
+        plugins: [
+            prae::impl_ts,
+        ];
+    }
+
+    prae::define! {
+        #[derive(Debug)]
+        Scores: Vec<u32>;
+        plugins: [
+            prae::impl_ts,
+        ];
+    }
+
+    #[derive(TS)]
+    #[allow(dead_code)]
+    struct User {
+        name: Username,
+        scores: Scores,
+    }
+
+    #[test]
+    fn ts_type_delegates_to_inner() {
+        let cfg = Config::default();
+        assert_eq!(Username::inline(&cfg), "string");
+        assert_eq!(Scores::inline(&cfg), "Array<number>");
+    }
+
+    #[test]
+    fn ts_type_works_inside_struct() {
+        let cfg = Config::default();
+        assert_eq!(
+            User::decl(&cfg),
+            "type User = { name: string, scores: Array<number>, };"
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use prae::Wrapper;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct UsernameError;
+
+impl fmt::Display for UsernameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "username is empty")
+    }
+}
+
+impl Error for UsernameError {}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    validate(UsernameError) |u| {
+        if u.is_empty() {
+            Err(UsernameError)
+        } else {
+            Ok(())
+        }
+    };
+}
+
+#[test]
+fn construction_error_has_inner_source() {
+    let err = Username::new("").unwrap_err();
+    let source = err.inner_source().unwrap();
+    assert_eq!(source.to_string(), "username is empty");
+    assert!(source.downcast_ref::<UsernameError>().is_some());
+}
+
+#[test]
+fn mutation_error_has_inner_source() {
+    let mut u = Username::new("user").unwrap();
+    let err = u.mutate(|u| u.clear()).unwrap_err();
+    assert!(err.inner_source().unwrap().is::<UsernameError>());
+}
+
+#[test]
+fn verification_error_has_inner_source() {
+    let err = Username::new_unprocessed("").verify().unwrap_err();
+    assert!(err.inner_source().unwrap().is::<UsernameError>());
+}
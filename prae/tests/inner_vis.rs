@@ -0,0 +1,48 @@
+mod types {
+    prae::define! {
+        #[derive(Debug)]
+        pub Username: pub(crate) String;
+        ensure |u: &String| !u.is_empty();
+    }
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Coords: pub(crate) (i64, i64);
+    }
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Tags<T: Clone>: pub(crate) Vec<T>;
+    }
+
+    prae::extend! {
+        #[derive(Debug)]
+        pub ShortUsername: pub(crate) Username;
+        ensure |u: &String| u.len() <= 8;
+    }
+
+    prae::extend! {
+        #[derive(Debug)]
+        pub Profile: pub(crate) Username as (String, u8);
+        project 0;
+    }
+}
+
+use prae::Wrapper;
+use types::*;
+
+#[test]
+fn inner_field_has_the_given_visibility() {
+    assert_eq!(Username::new("user").unwrap().0, "user");
+    assert_eq!(Coords::new((1i64, 2i64)).unwrap().0, (1, 2));
+    assert_eq!(Tags::new(vec![1]).unwrap().0, vec![1]);
+    assert_eq!(ShortUsername::new("user").unwrap().0, "user");
+    assert_eq!(Profile::new(("user".to_owned(), 3)).unwrap().0 .1, 3);
+}
+
+#[test]
+fn constructors_still_validate() {
+    assert!(Username::new("").is_err());
+    assert!(ShortUsername::new("long username").is_err());
+    assert!(Profile::new((String::new(), 3)).is_err());
+}
@@ -0,0 +1,57 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Retries: u32;
+    ensure |r| *r <= 10;
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    ensure |u: &String| !u.is_empty();
+}
+
+#[test]
+fn default_value_is_detected() {
+    assert!(Retries::new(0u32).unwrap().is_default());
+    assert!(!Retries::new(3u32).unwrap().is_default());
+}
+
+#[test]
+fn invalid_default_is_never_detected() {
+    assert!(!Username::new("user").unwrap().is_default());
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use prae::Wrapper;
+    use serde::Serialize;
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Timeout: u64;
+        ensure |t| *t <= 3600;
+        plugins: [
+            prae::impl_serde,
+        ];
+    }
+
+    #[derive(Serialize)]
+    struct Config {
+        #[serde(skip_serializing_if = "Wrapper::is_default")]
+        timeout: Timeout,
+    }
+
+    #[test]
+    fn default_value_is_skipped() {
+        let c = Config {
+            timeout: Timeout::new(0u64).unwrap(),
+        };
+        assert_eq!(serde_json::to_string(&c).unwrap(), "{}");
+        let c = Config {
+            timeout: Timeout::new(30u64).unwrap(),
+        };
+        assert_eq!(serde_json::to_string(&c).unwrap(), r#"{"timeout":30}"#);
+    }
+}
@@ -0,0 +1,41 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    Text: String;
+    adjust |t| *t = t.trim().to_owned();
+    ensure("text is empty") |t| !t.is_empty();
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    Sentence: Text;
+    ensure("sentence has no ending punctuation mark") |s| s.ends_with(&['.', '!', '?'][..]);
+}
+
+#[test]
+fn is_valid_checks_without_constructing() {
+    assert!(Text::is_valid(&"text".to_owned()));
+    assert!(!Text::is_valid(&"".to_owned()));
+}
+
+#[test]
+fn is_valid_doesnt_adjust() {
+    // Would be adjusted into an empty string by `Text::new`.
+    assert!(Text::is_valid(&"   ".to_owned()));
+}
+
+#[test]
+fn check_returns_error() {
+    assert_eq!(Text::check(&"".to_owned()), Err("text is empty"));
+}
+
+#[test]
+fn check_includes_extended_validation() {
+    assert_eq!(Sentence::check(&"".to_owned()), Err("text is empty"));
+    assert_eq!(
+        Sentence::check(&"No punctuation".to_owned()),
+        Err("sentence has no ending punctuation mark")
+    );
+    assert_eq!(Sentence::check(&"A sentence.".to_owned()), Ok(()));
+}
@@ -0,0 +1,44 @@
+#[cfg(feature = "regex")]
+mod tests {
+    use prae::Wrapper;
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Slug: String;
+        adjust |s| *s = s.trim().to_owned();
+        ensure prae::matches!(r"^[a-z0-9]+(-[a-z0-9]+)*$");
+    }
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Code: &'static str;
+        ensure("code must be three uppercase letters") prae::matches!("^[A-Z]{3}$");
+    }
+
+    #[test]
+    fn matching_value_passes() {
+        assert_eq!(Slug::new(" my-post ").unwrap().get(), "my-post");
+        assert!(Code::new("ABC").is_ok());
+    }
+
+    #[test]
+    fn non_matching_value_fails() {
+        assert_eq!(
+            Slug::new("My post").unwrap_err().original,
+            "value is invalid"
+        );
+        let err = Code::new("abcd").unwrap_err();
+        assert_eq!(err.original, "code must be three uppercase letters");
+    }
+
+    #[test]
+    fn pattern_is_checked_on_every_validation() {
+        for _ in 0..100 {
+            assert!(Slug::new("a-b").is_ok());
+            assert!(Slug::new("a--b").is_err());
+        }
+        let mut slug = Slug::new("a").unwrap();
+        assert!(slug.mutate(|s| s.push('!')).is_err());
+        assert_eq!(slug.get(), "a");
+    }
+}
@@ -0,0 +1,51 @@
+use prae::Wrapper;
+use std::collections::HashMap;
+
+const FORBIDDEN: [&str; 2] = ["password", "secret"];
+
+prae::define! {
+    #[derive(Debug)]
+    pub Config: HashMap<String, String>;
+    ensure("config contains a forbidden key") |c: &HashMap<String, String>| {
+        c.len() <= 3 && !FORBIDDEN.iter().any(|k| c.contains_key(*k))
+    };
+}
+
+fn map(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn merge_succeeds() {
+    let mut config = Config::new(map(&[("host", "localhost")])).unwrap();
+    config
+        .merge_checked(map(&[("port", "8080"), ("host", "example.com")]))
+        .unwrap();
+    assert_eq!(
+        config.get(),
+        &map(&[("host", "example.com"), ("port", "8080")])
+    );
+}
+
+#[test]
+fn merge_with_forbidden_key_is_rolled_back() {
+    let mut config = Config::new(map(&[("host", "localhost")])).unwrap();
+    let err = config
+        .merge_checked(map(&[("port", "8080"), ("secret", "1234")]))
+        .unwrap_err();
+    assert_eq!(err.original, "config contains a forbidden key");
+    assert!(err.new_value.contains_key("secret"));
+    assert_eq!(config.get(), &map(&[("host", "localhost")]));
+}
+
+#[test]
+fn merge_exceeding_size_limit_is_rolled_back() {
+    let mut config = Config::new(map(&[("a", "1"), ("b", "2")])).unwrap();
+    assert!(config
+        .merge_checked(map(&[("c", "3"), ("d", "4")]))
+        .is_err());
+    assert_eq!(config.get().len(), 2);
+}
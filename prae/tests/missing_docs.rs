@@ -0,0 +1,34 @@
+//! The generated public API must be documented.
+#![deny(missing_docs)]
+
+use prae::Wrapper;
+
+prae::define! {
+    /// A percentage.
+    #[derive(Debug)]
+    pub Percent: u8;
+    ensure_range(0, 100);
+    forward(is_power_of_two -> bool);
+}
+
+prae::define! {
+    /// A port that is known at compile time.
+    #[derive(Debug)]
+    pub Port: u16;
+    ensure const |port: &u16| *port >= 1024;
+}
+
+prae::define! {
+    /// A non-empty list.
+    #[derive(Debug)]
+    pub NonEmpty<T>: Vec<T>;
+    ensure |v| !v.is_empty();
+}
+
+#[test]
+fn documented_wrappers_compile_with_missing_docs_denied() {
+    assert!(Percent::in_range(42));
+    assert!(Percent::new(64u8).unwrap().is_power_of_two());
+    assert_eq!(*Port::new_const(8080).get(), 8080);
+    assert!(NonEmpty::new(vec![1]).is_ok());
+}
@@ -0,0 +1,92 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Slug: String;
+    adjust prae::adjust::trim;
+    adjust |s| s.make_ascii_lowercase();
+    adjust |s| *s = s.split_whitespace().collect::<Vec<_>>().join("-");
+    ensure("slug is empty") |s: &String| !s.is_empty();
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Steps: Vec<u8>;
+    adjust |v| v.push(1);
+    adjust |v| v.push(2);
+    adjust |v| v.push(3);
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Padded<T: Clone>: Vec<T>;
+    adjust |v: &mut Vec<T>| v.reverse();
+    adjust |v: &mut Vec<T>| v.truncate(2);
+    ensure |v: &Vec<T>| !v.is_empty();
+}
+
+#[test]
+fn adjust_closures_run_in_order() {
+    assert_eq!(Steps::new(vec![]).unwrap().get(), &[1, 2, 3]);
+}
+
+#[test]
+fn adjust_pipeline_runs_before_validation() {
+    assert_eq!(Slug::new("  Hello   World ").unwrap().get(), "hello-world");
+    assert_matches::assert_matches!(Slug::new("   "), Err(e) if e.original == "slug is empty");
+
+    let mut slug = Slug::new("a").unwrap();
+    slug.set(" Rust  Lang ");
+    assert_eq!(slug.get(), "rust-lang");
+}
+
+#[test]
+fn generic_wrappers_accept_multiple_adjust() {
+    assert_eq!(Padded::new(vec![1, 2, 3]).unwrap().get(), &[3, 2]);
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    pub ShortSlug: Slug;
+    adjust |s| s.truncate(8);
+    adjust |s| *s = s.trim_end_matches('-').to_owned();
+    ensure |s: &String| !s.is_empty();
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct NotADigit;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Digits: Vec<u8>;
+    adjust |v| v.push(1);
+    adjust |v| v.push(2);
+    adjust(NotADigit) |v: &mut Vec<u8>| {
+        if v.iter().all(|d| *d < 10) {
+            v.push(3);
+            Ok(())
+        } else {
+            Err(NotADigit)
+        }
+    };
+    adjust |v| v.push(4);
+    adjust |v| v.push(5);
+}
+
+#[test]
+fn extend_accepts_multiple_adjust() {
+    let slug = ShortSlug::new(" Hello World ").unwrap();
+    assert_eq!(slug.get(), "hello-wo");
+    let slug = ShortSlug::new("Rust Lang").unwrap();
+    assert_eq!(slug.get(), "rust-lan");
+    let slug = ShortSlug::new("Rustics X").unwrap();
+    assert_eq!(slug.get(), "rustics");
+}
+
+#[test]
+fn fallible_adjust_keeps_declaration_order() {
+    assert_eq!(Digits::new(vec![]).unwrap().get(), &[1, 2, 3, 4, 5]);
+    let err = Digits::new(vec![10]).unwrap_err();
+    assert_eq!(err.original, NotADigit);
+    assert_eq!(err.value, [10, 1, 2]);
+}
@@ -0,0 +1,28 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    adjust |u| *u = u.trim().to_owned();
+    ensure |u: &String| !u.is_empty();
+}
+
+#[test]
+fn all_valid_values_are_constructed_in_order() {
+    let users = Username::new_all(vec![" alice", "bob "]).unwrap();
+    let users: Vec<_> = users.iter().map(Username::get).collect();
+    assert_eq!(users, ["alice", "bob"]);
+}
+
+#[test]
+fn every_failure_is_collected() {
+    let errs = Username::new_all(["alice", " ", "bob", ""]).unwrap_err();
+    let values: Vec<_> = errs.iter().map(|e| e.value.as_str()).collect();
+    assert_eq!(values, ["", ""]);
+}
+
+#[test]
+fn empty_input_is_ok() {
+    let users = Username::new_all(Vec::<String>::new()).unwrap();
+    assert!(users.is_empty());
+}
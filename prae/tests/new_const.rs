@@ -0,0 +1,38 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Port: u16;
+    ensure const |port: &u16| *port >= 1024;
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Code: &'static str;
+    ensure const |c| c.len() == 3;
+    plugins: [
+        prae::impl_display,
+    ];
+}
+
+const PORT: Port = Port::new_const(8080);
+const CODE: Code = Code::new_const("abc");
+
+#[test]
+fn const_values_are_constructed() {
+    assert_eq!(*PORT.get(), 8080);
+    assert_eq!(CODE.to_string(), "abc");
+}
+
+#[test]
+fn runtime_construction_uses_same_check() {
+    assert!(Port::new(8081u16).is_ok());
+    assert_eq!(Port::new(80u16).unwrap_err().original, "value is invalid");
+    assert!(Code::new("abcd").is_err());
+}
+
+#[test]
+#[should_panic(expected = "value doesn't pass the const check of type Port")]
+fn new_const_panics_at_runtime_on_invalid_value() {
+    let _ = Port::new_const(std::hint::black_box(80));
+}
@@ -0,0 +1,19 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    Username: String;
+    adjust |u| *u = u.trim().to_owned();
+    ensure |u| !u.is_empty();
+}
+
+#[test]
+fn new_opt_returns_some_for_valid_data() {
+    let un = Username::new_opt(" user ").unwrap();
+    assert_eq!(un.get(), "user");
+}
+
+#[test]
+fn new_opt_returns_none_for_invalid_data() {
+    assert!(Username::new_opt("  ").is_none());
+}
@@ -0,0 +1,37 @@
+use assert_matches::assert_matches;
+use prae::{ConstructionError, RetainedInputError, Wrapper};
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    adjust |u| *u = u.trim().to_lowercase();
+    ensure |u| u.len() >= 3;
+}
+
+#[test]
+fn construction_succeeds_with_valid_data() {
+    assert_eq!(
+        Username::new_retaining_input(" User ").unwrap().get(),
+        "user"
+    );
+}
+
+#[test]
+fn construction_error_retains_input() {
+    assert_matches!(
+        Username::new_retaining_input("  AB "),
+        Err(RetainedInputError {
+            input,
+            error: ConstructionError { value, .. },
+        }) if input == "  AB " && value == "ab"
+    );
+}
+
+#[test]
+fn display_matches_construction_error() {
+    let err = Username::new_retaining_input(" X ").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        Username::new(" X ").unwrap_err().to_string()
+    );
+}
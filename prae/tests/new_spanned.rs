@@ -0,0 +1,63 @@
+use prae::{Spanned, Wrapper};
+use std::error::Error;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Ident: String;
+    adjust |i| *i = i.trim().to_owned();
+    ensure("identifier must be alphanumeric") |i: &String| {
+        !i.is_empty() && i.chars().all(char::is_alphanumeric)
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct LineCol {
+    line: usize,
+    col: usize,
+}
+
+#[test]
+fn valid_value_is_constructed() {
+    let ident = Ident::new_spanned(" abc ", 0..5).unwrap();
+    assert_eq!(ident.get(), "abc");
+}
+
+#[test]
+fn error_message_includes_span() {
+    let err = Ident::new_spanned("a b", 10..13).unwrap_err();
+    assert_eq!(err.span, 10..13);
+    assert_eq!(err.error.value, "a b");
+    assert_eq!(
+        err.to_string(),
+        "failed to construct type Ident from value \"a b\": identifier must be alphanumeric (at 10..13)"
+    );
+}
+
+#[test]
+fn custom_span_type() {
+    let err = Ident::new_spanned("", LineCol { line: 2, col: 5 }).unwrap_err();
+    assert_eq!(err.span, LineCol { line: 2, col: 5 });
+    assert!(err
+        .to_string()
+        .ends_with("(at LineCol { line: 2, col: 5 })"));
+}
+
+#[derive(Debug)]
+struct ParseError;
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "parse error")
+    }
+}
+
+impl Error for ParseError {}
+
+#[test]
+fn spanned_error_has_source() {
+    let err = Spanned {
+        span: 1..2,
+        error: ParseError,
+    };
+    assert!(err.source().unwrap().is::<ParseError>());
+}
@@ -1,3 +1,5 @@
+use prae::Wrapper;
+
 struct User {
     name: String,
 }
@@ -6,3 +8,30 @@ prae::define! {
     ValidUser: User;
     ensure |u| !u.name.is_empty();
 }
+
+#[test]
+fn mutation_in_place_works_without_clone() {
+    let u = ValidUser::new(User {
+        name: "user".to_owned(),
+    })
+    .unwrap_or_else(|_| panic!("user is invalid"));
+    let u = u
+        .mutate_in_place(|u| u.name.push('!'))
+        .unwrap_or_else(|_| panic!("user is invalid"));
+    assert_eq!(u.get().name, "user!");
+}
+
+#[test]
+fn failed_mutation_in_place_returns_new_value() {
+    let u = ValidUser::new(User {
+        name: "user".to_owned(),
+    })
+    .unwrap_or_else(|_| panic!("user is invalid"));
+    let err = match u.mutate_in_place(|u| u.name.clear()) {
+        Ok(_) => panic!("user is valid"),
+        Err(err) => err,
+    };
+    assert!(err.old_value.is_none());
+    assert_eq!(err.new_value.name, "");
+    assert_eq!(err.original, "value is invalid");
+}
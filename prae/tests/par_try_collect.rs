@@ -0,0 +1,34 @@
+#[cfg(feature = "rayon")]
+mod tests {
+    use assert_matches::assert_matches;
+    use prae::Wrapper;
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Even: u32;
+        ensure |n| n % 2 == 0;
+    }
+
+    #[test]
+    fn collection_succeeds_for_valid_values() {
+        let values: Vec<u32> = (0..100_000).map(|n| n * 2).collect();
+        let evens = prae::par_try_collect::<Even, _>(values).unwrap();
+        assert_eq!(evens.len(), 100_000);
+        assert!(evens
+            .iter()
+            .enumerate()
+            .all(|(i, e)| *e.get() == i as u32 * 2));
+    }
+
+    #[test]
+    fn collection_reports_lowest_failing_index() {
+        let mut values: Vec<u32> = (0..100_000).map(|n| n * 2).collect();
+        values[99_999] = 1;
+        values[50_001] = 3;
+        values[70_000] = 5;
+        assert_matches!(
+            prae::par_try_collect::<Even, _>(values),
+            Err((50_001, prae::ConstructionError { value: 3, .. }))
+        );
+    }
+}
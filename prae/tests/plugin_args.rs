@@ -0,0 +1,79 @@
+use prae::Wrapper;
+
+macro_rules! impl_display_with_prefix {
+    ($wrapper:ident) => {
+        impl_display_with_prefix!($wrapper, prefix = "");
+    };
+    ($wrapper:ident, prefix = $prefix:literal) => {
+        impl ::std::fmt::Display for $wrapper {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "{}{}", $prefix, self.get())
+            }
+        }
+    };
+}
+
+mod plugins {
+    macro_rules! impl_max_len {
+        ($wrapper:ident, $max:expr) => {
+            impl $wrapper {
+                pub const MAX_LEN: usize = $max;
+            }
+        };
+    }
+    pub(crate) use impl_max_len;
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    ensure |u| u.len() <= Username::MAX_LEN;
+    plugins: [
+        impl_display_with_prefix(prefix = "user:"),
+        plugins::impl_max_len(8),
+    ];
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Plain: String;
+    plugins: [
+        impl_display_with_prefix,
+    ];
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    pub Admin: Username;
+    plugins: [
+        impl_display_with_prefix(prefix = "admin:"),
+    ];
+}
+
+#[test]
+fn arguments_are_forwarded_to_plugins() {
+    assert_eq!(Username::new("bob").unwrap().to_string(), "user:bob");
+    assert_eq!(Username::MAX_LEN, 8);
+    assert!(Username::new("long username").is_err());
+    assert_eq!(Admin::new("root").unwrap().to_string(), "admin:root");
+}
+
+#[test]
+fn plugins_without_arguments_still_work() {
+    assert_eq!(Plain::new("text").unwrap().to_string(), "text");
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Absolute: String;
+    plugins: [
+        ::prae::impl_display,
+        ::prae::impl_hash_eq_by(|a| a.to_lowercase()),
+    ];
+}
+
+#[test]
+fn absolute_plugin_paths_are_accepted() {
+    assert_eq!(Absolute::new("Text").unwrap().to_string(), "Text");
+    assert_eq!(Absolute::new("a").unwrap(), Absolute::new("A").unwrap());
+}
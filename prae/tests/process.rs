@@ -0,0 +1,40 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    adjust |u| *u = u.trim().to_owned();
+    ensure |u: &String| !u.is_empty();
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    pub ShortUsername: Username;
+    adjust |u| *u = u.to_lowercase();
+    ensure |u: &String| u.len() <= 5;
+}
+
+#[test]
+fn valid_value_is_adjusted() {
+    let mut value = "  alice ".to_owned();
+    assert!(Username::process(&mut value).is_ok());
+    assert_eq!(value, "alice");
+}
+
+#[test]
+fn invalid_value_returns_error() {
+    let mut value = "   ".to_owned();
+    assert_eq!(Username::process(&mut value), Err("value is invalid"));
+}
+
+#[test]
+fn extended_wrapper_runs_parent_process() {
+    let mut value = " ALICE ".to_owned();
+    assert!(ShortUsername::process(&mut value).is_ok());
+    assert_eq!(value, "alice");
+
+    let mut value = " ".to_owned();
+    assert!(ShortUsername::process(&mut value).is_err());
+    let mut value = "Alexander".to_owned();
+    assert!(ShortUsername::process(&mut value).is_err());
+}
@@ -0,0 +1,187 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use prae::{ProcessPhase, Rejection, Wrapper};
+
+prae::define! {
+    #[derive(Debug)]
+    pub Text: String;
+    adjust |t| *t = t.trim().to_owned();
+    ensure |t: &String| !t.is_empty();
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    pub Word: Text;
+    ensure |w: &String| !w.contains(' ');
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    pub ShortWord: Word;
+    ensure |w: &String| w.len() <= 5;
+}
+
+#[derive(Debug)]
+pub struct Point {
+    word: String,
+    x: i32,
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    pub PositiveWordPoint: Word as Point;
+    project word;
+    ensure |p: &Point| p.x > 0;
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Port: String;
+    adjust(&'static str) |p: &mut String| {
+        *p = p.trim().to_owned();
+        if p.chars().all(|c| c.is_ascii_digit()) {
+            Ok(())
+        } else {
+            Err("port must be a number")
+        }
+    };
+    ensure |p: &String| !p.is_empty();
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    pub Blank: Text;
+    adjust |t| t.clear();
+    ensure |t: &String| t.is_empty();
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    pub NeverBlank: Blank;
+    ensure |t: &String| !t.is_empty();
+}
+
+static COUNTED_CHECKS: AtomicUsize = AtomicUsize::new(0);
+
+prae::define! {
+    #[derive(Debug)]
+    pub Counted: String;
+    ensure |c: &String| {
+        COUNTED_CHECKS.fetch_add(1, Ordering::SeqCst);
+        !c.is_empty()
+    };
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    pub CountedWord: Counted;
+    ensure |w: &String| !w.contains(' ');
+}
+
+#[test]
+fn define_errors_are_from_validate_phase() {
+    let err = Text::new("   ").unwrap_err();
+    assert_eq!(err.phase(), ProcessPhase::Validate);
+}
+
+#[test]
+fn multi_level_extend_identifies_failing_phase() {
+    let err = ShortWord::new("   ").unwrap_err();
+    assert_eq!(err.phase(), ProcessPhase::Extend);
+    // Every level of the chain reports where it has rejected the value.
+    let phase = |process: fn(&mut String) -> Result<(), (&'static str, Rejection)>| {
+        process(&mut err.value.clone()).unwrap_err().1.phase
+    };
+    assert_eq!(phase(Word::PROCESS_TRACED), ProcessPhase::Extend);
+    assert_eq!(phase(Text::PROCESS_TRACED), ProcessPhase::Validate);
+
+    let err = ShortWord::new("two words").unwrap_err();
+    assert_eq!(err.phase(), ProcessPhase::Extend);
+    let mut value = err.value.clone();
+    let (_, rejection) = Word::PROCESS_TRACED(&mut value).unwrap_err();
+    assert_eq!(rejection.phase, ProcessPhase::Validate);
+
+    let err = ShortWord::new("lengthy").unwrap_err();
+    assert_eq!(err.phase(), ProcessPhase::Validate);
+}
+
+#[test]
+fn widened_extend_identifies_failing_phase() {
+    let err = PositiveWordPoint::new(Point {
+        word: "two words".to_owned(),
+        x: 1,
+    })
+    .unwrap_err();
+    assert_eq!(err.phase(), ProcessPhase::Extend);
+
+    let err = PositiveWordPoint::new(Point {
+        word: "word".to_owned(),
+        x: 0,
+    })
+    .unwrap_err();
+    assert_eq!(err.phase(), ProcessPhase::Validate);
+}
+
+#[test]
+fn failed_wrapper_names_the_rejecting_level() {
+    assert_eq!(Text::new("   ").unwrap_err().failed_wrapper(), "Text");
+    assert_eq!(ShortWord::new("   ").unwrap_err().failed_wrapper(), "Text");
+    assert_eq!(
+        ShortWord::new("two words").unwrap_err().failed_wrapper(),
+        "Word"
+    );
+    assert_eq!(
+        ShortWord::new("lengthy").unwrap_err().failed_wrapper(),
+        "ShortWord"
+    );
+}
+
+#[test]
+fn widened_failed_wrapper_names_the_rejecting_level() {
+    let err = PositiveWordPoint::new(Point {
+        word: " ".to_owned(),
+        x: 1,
+    })
+    .unwrap_err();
+    assert_eq!(err.failed_wrapper(), "Text");
+
+    let err = PositiveWordPoint::new(Point {
+        word: "word".to_owned(),
+        x: 0,
+    })
+    .unwrap_err();
+    assert_eq!(err.failed_wrapper(), "PositiveWordPoint");
+}
+
+#[test]
+fn fallible_adjust_errors_are_from_adjust_phase() {
+    let err = Port::new("80a").unwrap_err();
+    assert_eq!(err.phase(), ProcessPhase::Adjust);
+    let err = Port::new(" ").unwrap_err();
+    assert_eq!(err.phase(), ProcessPhase::Validate);
+}
+
+#[test]
+fn phase_is_recorded_during_processing() {
+    // The own `adjust` of `NeverBlank`'s parent empties the value, which
+    // `Text` wouldn't accept anymore. Still, it was `NeverBlank` itself that
+    // has rejected it.
+    let err = NeverBlank::new("text").unwrap_err();
+    assert_eq!(err.value, "");
+    assert_eq!(err.phase(), ProcessPhase::Validate);
+}
+
+#[test]
+fn value_is_validated_once() {
+    COUNTED_CHECKS.store(0, Ordering::SeqCst);
+    let err = CountedWord::new("").unwrap_err();
+    assert_eq!(err.phase(), ProcessPhase::Extend);
+    assert_eq!(err.failed_wrapper(), "Counted");
+    assert_eq!(COUNTED_CHECKS.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn failed_wrapper_is_recorded_during_processing() {
+    let err = NeverBlank::new("text").unwrap_err();
+    assert_eq!(err.failed_wrapper(), "NeverBlank");
+}
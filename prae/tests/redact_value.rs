@@ -0,0 +1,59 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Password: String;
+    ensure |p: &String| p.len() >= 8;
+    redact_value;
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    pub StrongPassword: Password;
+    ensure |p: &String| p.chars().any(|c| c.is_ascii_digit());
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    ensure |u: &String| !u.is_empty();
+}
+
+#[test]
+fn construction_error_redacts_value() {
+    let err = Password::new("hunter2").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to construct type Password from value <redacted>: value is invalid"
+    );
+    assert_eq!(err.value, "hunter2");
+}
+
+#[test]
+fn mutation_error_redacts_values() {
+    let mut p = Password::new("correct horse").unwrap();
+    let err = p.mutate(|p| p.truncate(3)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to mutate type Password from value <redacted> to value <redacted>: value is invalid"
+    );
+    assert!(!err.to_string().contains("correct"));
+}
+
+#[test]
+fn extended_wrapper_inherits_redaction() {
+    let err = StrongPassword::new("correct horse").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to construct type StrongPassword from value <redacted>: value is invalid"
+    );
+}
+
+#[test]
+fn value_is_not_redacted_by_default() {
+    let err = Username::new("").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to construct type Username from value \"\": value is invalid"
+    );
+}
@@ -0,0 +1,27 @@
+#![allow(deprecated)]
+
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    adjust |u| *u = u.trim().to_owned();
+    ensure |u| !u.is_empty();
+}
+
+#[test]
+fn renormalization_adjusts_value_in_place() {
+    let mut u = Username::new("user").unwrap();
+    u.mutate_unprocessed(|u| u.push_str("name  "));
+    assert_eq!(u.get(), "username  ");
+    u.renormalize().unwrap();
+    assert_eq!(u.get(), "username");
+}
+
+#[test]
+fn renormalization_reports_invalid_value() {
+    let mut u = Username::new("user").unwrap();
+    u.mutate_unprocessed(|u| *u = "   ".to_owned());
+    assert_eq!(u.renormalize(), Err("value is invalid"));
+    assert_eq!(u.get(), "");
+}
@@ -0,0 +1,49 @@
+use assert_matches::assert_matches;
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Port: i32;
+    ensure_range(1, 65535);
+    repair |p| if *p < 1 { Some(1) } else { None };
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Broken: i32;
+    ensure |n| *n > 0;
+    repair |n| Some(*n - 1);
+}
+
+#[test]
+fn valid_value_is_not_repaired() {
+    assert_eq!(Port::new(8080).unwrap().get(), &8080);
+}
+
+#[test]
+fn invalid_value_is_repaired() {
+    assert_eq!(Port::new(-5).unwrap().get(), &1);
+    let mut p = Port::new(80).unwrap();
+    p.set(0);
+    assert_eq!(p.get(), &1);
+}
+
+#[test]
+fn unrepairable_value_is_rejected() {
+    assert_matches!(
+        Port::new(70000),
+        Err(prae::ConstructionError {
+            value: 70000,
+            original: "value is out of range",
+            ..
+        })
+    );
+}
+
+#[test]
+fn repair_is_attempted_only_once() {
+    assert_matches!(
+        Broken::new(0),
+        Err(prae::ConstructionError { value: -1, .. })
+    );
+}
@@ -0,0 +1,49 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    adjust |u| *u = u.trim().to_owned();
+    ensure |u: &String| !u.is_empty();
+}
+
+#[derive(Debug)]
+pub struct TransitionError;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Version: u32;
+    validate_transition(TransitionError) |old, new| {
+        if new > old {
+            Ok(())
+        } else {
+            Err(TransitionError)
+        }
+    };
+}
+
+#[test]
+fn replace_returns_old_value() {
+    let mut u = Username::new("old").unwrap();
+    let old = u.replace("  new  ").unwrap();
+    assert_eq!(old, "old");
+    assert_eq!(u.get(), "new");
+}
+
+#[test]
+fn replace_leaves_wrapper_untouched_on_error() {
+    let mut u = Username::new("old").unwrap();
+    let err = u.replace("   ").unwrap_err();
+    assert_eq!(err.value, "");
+    assert_eq!(err.original, "value is invalid");
+    assert_eq!(u.get(), "old");
+}
+
+#[test]
+fn replace_validates_transition() {
+    let mut v = Version::new(1u32).unwrap();
+    assert_eq!(v.replace(2u32).unwrap(), 1);
+    let err = v.replace(1u32).unwrap_err();
+    assert_eq!(err.value, 1);
+    assert_eq!(*v.get(), 2);
+}
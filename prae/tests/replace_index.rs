@@ -0,0 +1,36 @@
+use assert_matches::assert_matches;
+use prae::{ReplaceError, Wrapper};
+
+prae::define! {
+    #[derive(Debug)]
+    pub Numbers: Vec<u64>;
+    ensure |n| n.iter().sum::<u64>() <= 10;
+}
+
+#[test]
+fn replacement_returns_old_element() {
+    let mut n = Numbers::new(vec![1, 2, 3]).unwrap();
+    assert_eq!(n.replace_index(1, 5).unwrap(), 2);
+    assert_eq!(n.get(), &vec![1, 5, 3]);
+}
+
+#[test]
+fn replacement_fails_for_out_of_bounds_index() {
+    let mut n = Numbers::new(vec![1, 2, 3]).unwrap();
+    assert_matches!(
+        n.replace_index(3, 0),
+        Err(ReplaceError::OutOfBounds { index: 3, len: 3 })
+    );
+    assert_eq!(n.get(), &vec![1, 2, 3]);
+}
+
+#[test]
+fn replacement_rolls_back_invalid_value() {
+    let mut n = Numbers::new(vec![1, 2, 3]).unwrap();
+    assert_matches!(
+        n.replace_index(0, 10),
+        Err(ReplaceError::Mutation(prae::MutationError { new_value, .. }))
+            if new_value == vec![10, 2, 3]
+    );
+    assert_eq!(n.get(), &vec![1, 2, 3]);
+}
@@ -0,0 +1,42 @@
+#[cfg(feature = "std")]
+mod tests {
+    use prae::Wrapper;
+    use std::collections::HashMap;
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Headers: HashMap<String, String>;
+        ensure("headers must contain Host") |h: &HashMap<String, String>| h.contains_key("Host");
+    }
+
+    fn headers(pairs: &[(&str, &str)]) -> Headers {
+        Headers::new(
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<HashMap<_, _>>(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn retain_filters_headers() {
+        let mut h = headers(&[("Host", "example.com"), ("X-Debug", "1"), ("Accept", "*/*")]);
+        h.retain_keys_checked(|k, _| !k.starts_with("X-")).unwrap();
+        let mut keys: Vec<_> = h.get().keys().map(String::as_str).collect();
+        keys.sort();
+        assert_eq!(keys, ["Accept", "Host"]);
+    }
+
+    #[test]
+    fn retain_violating_invariant_is_rolled_back() {
+        const ALLOWED: [&str; 1] = ["Accept"];
+        let mut h = headers(&[("Host", "example.com"), ("Accept", "*/*")]);
+        let err = h
+            .retain_keys_checked(|k, _| ALLOWED.contains(&k.as_str()))
+            .unwrap_err();
+        assert_eq!(err.original, "headers must contain Host");
+        assert!(!err.new_value.contains_key("Host"));
+        assert_eq!(h.get().len(), 2);
+    }
+}
@@ -0,0 +1,77 @@
+use prae::rules::{self, Length};
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    adjust prae::adjust::trim;
+    ensure rules::len_between::<3, 8>;
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Offset: i16;
+    ensure rules::in_range::<{ -10 }, 10>;
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub PageSize: usize;
+    ensure rules::in_range::<1, 100>;
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Huge: u128;
+    ensure rules::in_range::<0, { i128::MAX }>;
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Tags: Vec<String>;
+    ensure |tags| rules::non_empty(tags) && tags.iter().all(rules::non_empty);
+}
+
+#[test]
+fn len_between_is_inclusive() {
+    assert!(Username::new("ab").is_err());
+    assert!(Username::new("abc").is_ok());
+    assert!(Username::new(" abcdefgh ").is_ok());
+    assert!(Username::new("abcdefghi").is_err());
+}
+
+#[test]
+fn in_range_is_inclusive() {
+    assert!(Offset::new(-11i16).is_err());
+    assert!(Offset::new(-10i16).is_ok());
+    assert!(Offset::new(10i16).is_ok());
+    assert!(Offset::new(11i16).is_err());
+}
+
+#[test]
+fn in_range_supports_usize() {
+    assert!(PageSize::new(0usize).is_err());
+    assert!(PageSize::new(1usize).is_ok());
+    assert!(PageSize::new(100usize).is_ok());
+    assert!(PageSize::new(101usize).is_err());
+}
+
+#[test]
+fn in_range_rejects_u128_out_of_i128() {
+    assert!(Huge::new(i128::MAX as u128).is_ok());
+    assert!(Huge::new(u128::MAX).is_err());
+}
+
+#[test]
+fn non_empty_composes() {
+    assert!(Tags::new(vec![]).is_err());
+    assert!(Tags::new(vec![String::new()]).is_err());
+    assert!(Tags::new(vec!["rust".to_owned()]).is_ok());
+}
+
+#[test]
+fn length_counts_bytes_and_elements() {
+    assert_eq!("é".length(), 2);
+    assert_eq!([1, 2, 3].length(), 3);
+    assert_eq!(vec![(); 4].length(), 4);
+}
@@ -0,0 +1,74 @@
+#[cfg(feature = "serde")]
+mod tests {
+    use prae::Wrapper;
+    use serde::{Deserialize, Serialize};
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Username: String;
+        adjust prae::adjust::trim;
+        ensure |u: &String| !u.is_empty();
+        plugins: [
+            prae::impl_serde,
+        ];
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Profile {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        nickname: Option<Username>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Team {
+        #[serde(deserialize_with = "prae::deserialize_vec")]
+        members: Vec<Username>,
+    }
+
+    #[test]
+    fn optional_wrapper_is_skipped_when_none() {
+        let p = Profile { nickname: None };
+        assert_eq!(serde_json::to_string(&p).unwrap(), "{}");
+        let p = Profile {
+            nickname: Some(Username::new("bob").unwrap()),
+        };
+        assert_eq!(serde_json::to_string(&p).unwrap(), r#"{"nickname":"bob"}"#);
+    }
+
+    #[test]
+    fn optional_wrapper_is_validated_when_present() {
+        let p: Profile = serde_json::from_str("{}").unwrap();
+        assert!(p.nickname.is_none());
+        let p: Profile = serde_json::from_str(r#"{"nickname":null}"#).unwrap();
+        assert!(p.nickname.is_none());
+        let p: Profile = serde_json::from_str(r#"{"nickname":" bob "}"#).unwrap();
+        assert_eq!(p.nickname.unwrap().get(), "bob");
+        assert!(serde_json::from_str::<Profile>(r#"{"nickname":" "}"#).is_err());
+    }
+
+    #[test]
+    fn vec_elements_are_validated() {
+        let t: Team = serde_json::from_str(r#"{"members":[" alice ","bob"]}"#).unwrap();
+        let members: Vec<_> = t.members.iter().map(Username::get).collect();
+        assert_eq!(members, ["alice", "bob"]);
+        let t: Team = serde_json::from_str(r#"{"members":[]}"#).unwrap();
+        assert!(t.members.is_empty());
+    }
+
+    #[test]
+    fn vec_error_reports_index_of_first_failure() {
+        let err =
+            serde_json::from_str::<Team>(r#"{"members":["alice","bob"," ",""]}"#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid element at index 2: value is invalid at line 1 column 30"
+        );
+    }
+
+    #[test]
+    fn vec_type_errors_are_passed_through() {
+        let err = serde_json::from_str::<Team>(r#"{"members":["alice",1]}"#).unwrap_err();
+        assert!(err.to_string().starts_with("invalid type: integer `1`"));
+        assert!(serde_json::from_str::<Team>(r#"{"members":"alice"}"#).is_err());
+    }
+}
@@ -0,0 +1,20 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Tags: Vec<String>;
+    adjust prae::adjust::sort_dedup;
+    ensure |t: &Vec<String>| !t.is_empty();
+}
+
+#[test]
+fn to_inner_returns_an_independent_clone() {
+    let tags = Tags::new(vec!["b".to_owned(), "a".to_owned(), "b".to_owned()]).unwrap();
+    let mut owned = tags.to_inner();
+    assert_eq!(owned, ["a", "b"]);
+
+    // Modifying the clone doesn't bypass the validation of the wrapper.
+    owned.clear();
+    assert_eq!(tags.get(), &["a", "b"]);
+    assert!(Tags::new(owned).is_err());
+}
@@ -0,0 +1,43 @@
+#[cfg(feature = "serde_json")]
+mod tests {
+    use prae::Wrapper;
+    use serde::Serialize;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[derive(Debug, Serialize)]
+    pub struct Event {
+        name: String,
+        count: u32,
+    }
+
+    prae::define! {
+        #[derive(Debug)]
+        ValidEvent: Event;
+        ensure |e| !e.name.is_empty();
+    }
+
+    prae::define! {
+        #[derive(Debug)]
+        Points: HashMap<(i32, i32), u32>;
+    }
+
+    #[test]
+    fn conversion_succeeds() {
+        let e = ValidEvent::new(Event {
+            name: "login".to_owned(),
+            count: 3,
+        })
+        .unwrap();
+        assert_eq!(
+            e.to_json_value().unwrap(),
+            json!({ "name": "login", "count": 3 })
+        );
+    }
+
+    #[test]
+    fn conversion_fails_for_non_json_value() {
+        let p = Points::new(HashMap::from([((1, 2), 3)])).unwrap();
+        assert!(p.to_json_value().is_err());
+    }
+}
@@ -0,0 +1,61 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Stack: Vec<u32>;
+    adjust |s| s.sort_unstable();
+    ensure |s: &Vec<u32>| !s.is_empty();
+}
+
+#[derive(Debug)]
+pub struct Decrease;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Counter: u32;
+    validate_transition(Decrease) |old, new| {
+        if new >= old {
+            Ok(())
+        } else {
+            Err(Decrease)
+        }
+    };
+}
+
+#[test]
+fn transact_returns_result_of_closure() {
+    let mut stack = Stack::new(vec![3, 1, 2]).unwrap();
+    let popped = stack.transact(|s| s.pop()).unwrap();
+    assert_eq!(popped, Some(3));
+    assert_eq!(stack.get(), &[1, 2]);
+}
+
+#[test]
+fn transact_commits_adjusted_value() {
+    let mut stack = Stack::new(vec![2]).unwrap();
+    let len = stack
+        .transact(|s| {
+            s.push(1);
+            s.len()
+        })
+        .unwrap();
+    assert_eq!(len, 2);
+    assert_eq!(stack.get(), &[1, 2]);
+}
+
+#[test]
+fn transact_rolls_back_on_failure() {
+    let mut stack = Stack::new(vec![1]).unwrap();
+    let err = stack.transact(|s| s.pop()).unwrap_err();
+    assert_eq!(err.old_value.unwrap(), [1]);
+    assert!(err.new_value.is_empty());
+    assert_eq!(stack.get(), &[1]);
+}
+
+#[test]
+fn transact_validates_transition() {
+    let mut c = Counter::new(5u32).unwrap();
+    assert_eq!(c.transact(|c| std::mem::replace(c, 6)).unwrap(), 5);
+    assert!(c.transact(|c| *c -= 1).is_err());
+    assert_eq!(*c.get(), 6);
+}
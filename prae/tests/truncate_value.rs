@@ -0,0 +1,113 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Frame: Vec<u8>;
+    ensure |f: &Vec<u8>| f.first() == Some(&0x7e);
+    truncate_value(hex, 4);
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Packet: Box<[u8]>;
+    ensure |p| p.len() <= 4;
+    truncate_value(hex, 2);
+    plugins: [
+        prae::impl_deref,
+        prae::impl_index,
+    ];
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Comment: String;
+    ensure |c: &String| c.len() <= 8;
+    truncate_value(6);
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Secret: Vec<u8>;
+    ensure |s: &Vec<u8>| !s.is_empty();
+    redact_value;
+    truncate_value(hex, 4);
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    pub ShortFrame: Frame;
+    ensure |f: &Vec<u8>| f.len() <= 4;
+}
+
+#[test]
+fn long_bytes_are_truncated_as_hex() {
+    let err = Frame::new(vec![0xde, 0xad, 0xbe, 0xef, 0x00, 0x01]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to construct type Frame from value deadbeef…: value is invalid"
+    );
+    assert_eq!(err.value.len(), 6);
+}
+
+#[test]
+fn short_bytes_are_not_truncated() {
+    let err = Frame::new(vec![0x00, 0x01]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to construct type Frame from value 0001: value is invalid"
+    );
+}
+
+#[test]
+fn boxed_slices_are_truncated_as_hex() {
+    let err = Packet::new(vec![0xca, 0xfe, 0xba, 0xbe, 0x00].into_boxed_slice()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to construct type Packet from value cafe…: value is invalid"
+    );
+}
+
+#[test]
+fn debug_output_is_truncated_without_hex() {
+    let err = Comment::new("a very long comment").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to construct type Comment from value \"a ver…: value is invalid"
+    );
+}
+
+#[test]
+fn redaction_takes_precedence() {
+    let err = Secret::new(vec![]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to construct type Secret from value <redacted>: value is invalid"
+    );
+}
+
+#[test]
+fn mutation_errors_are_truncated() {
+    let mut frame = Frame::new(vec![0x7e]).unwrap();
+    let err = frame.mutate(|f| *f = vec![0xff; 8]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to mutate type Frame from value 7e to value ffffffff…: value is invalid"
+    );
+}
+
+#[test]
+fn extended_wrapper_inherits_truncation() {
+    let err = ShortFrame::new(vec![0x7e, 0x01, 0x02, 0x03, 0x04]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to construct type ShortFrame from value 7e010203…: value is invalid"
+    );
+}
+
+#[test]
+fn byte_plugins_work_with_boxed_slices() {
+    let packet = Packet::new(vec![0xca, 0xfe].into_boxed_slice()).unwrap();
+    assert_eq!(packet.len(), 2);
+    assert_eq!(packet[1], 0xfe);
+    assert_eq!(&packet[..1], [0xca]);
+}
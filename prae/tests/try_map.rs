@@ -0,0 +1,58 @@
+use assert_matches::assert_matches;
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    Username: String;
+    adjust |u| *u = u.trim().to_owned();
+    ensure |u| !u.is_empty() && u.len() <= 8;
+}
+
+#[test]
+fn try_map_succeeds_for_valid_data() {
+    let un = Username::new(" user ").unwrap();
+    let un = un.try_map(|u| u.to_uppercase()).unwrap();
+    assert_eq!(un.get(), "USER");
+}
+
+#[test]
+fn try_map_adjusts_transformed_value() {
+    let un = Username::new("user").unwrap();
+    let un = un.try_map(|u| format!("  {}  ", u)).unwrap();
+    assert_eq!(un.get(), "user");
+}
+
+#[test]
+fn try_map_fails_for_invalid_data() {
+    let un = Username::new("user").unwrap();
+    assert_matches!(
+        un.try_map(|u| u.repeat(3)),
+        Err(prae::ConstructionError { value, .. }) if value == "useruseruser"
+    );
+}
+
+struct User {
+    name: String,
+}
+
+prae::define! {
+    ValidUser: User;
+    ensure |u| !u.name.is_empty();
+}
+
+#[test]
+fn try_map_works_without_clone() {
+    let user = ValidUser::new(User {
+        name: "user".to_owned(),
+    })
+    .ok()
+    .unwrap();
+    let user = user
+        .try_map(|mut u| {
+            u.name.push_str("name");
+            u
+        })
+        .ok()
+        .unwrap();
+    assert_eq!(user.get().name, "username");
+}
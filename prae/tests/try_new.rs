@@ -0,0 +1,53 @@
+use assert_matches::assert_matches;
+use prae::{TryNewError, Wrapper};
+
+prae::define! {
+    #[derive(Debug)]
+    pub Percent: u8;
+    ensure("percent must be at most 100") |p| *p <= 100;
+}
+
+#[test]
+fn try_new_converts_and_validates() {
+    let p = Percent::try_new(42u32).unwrap();
+    assert_eq!(*p.get(), 42);
+}
+
+#[test]
+fn try_new_reports_conversion_failure() {
+    let err = Percent::try_new(300u32).unwrap_err();
+    assert_matches!(err, TryNewError::Conversion(_));
+    assert_eq!(
+        err.to_string(),
+        "failed to convert value to the inner type of Percent: out of range integral type conversion attempted"
+    );
+}
+
+#[test]
+fn try_new_reports_validation_failure() {
+    let err = Percent::try_new(101u32).unwrap_err();
+    assert_matches!(err, TryNewError::Construction(ref e) if e.value == 101);
+    assert_eq!(
+        err.to_string(),
+        "failed to construct type Percent from value 101: percent must be at most 100"
+    );
+}
+
+#[test]
+fn try_new_accepts_infallible_conversions() {
+    assert!(Percent::try_new(50u8).is_ok());
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Port: u16;
+    ensure |p: &u16| *p >= 1024;
+}
+
+#[test]
+fn try_new_narrows_signed_integers() {
+    assert_eq!(*Port::try_new(8080i64).unwrap().get(), 8080);
+    assert_matches!(Port::try_new(-1i64), Err(TryNewError::Conversion(_)));
+    assert_matches!(Port::try_new(65536i64), Err(TryNewError::Conversion(_)));
+    assert_matches!(Port::try_new(80i64), Err(TryNewError::Construction(_)));
+}
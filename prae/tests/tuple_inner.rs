@@ -0,0 +1,91 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Range: (i64, i64);
+    ensure |(lo, hi)| lo <= hi;
+    plugins: [
+        prae::impl_deref,
+    ];
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Span: (usize, usize);
+    adjust |(start, end)| {
+        if start > end {
+            std::mem::swap(start, end);
+        }
+    };
+    ensure_enum(SpanError) {
+        Empty => |(start, end)| start != end,
+    };
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rgb {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Gray: Rgb;
+    ensure |Rgb { r, g, b }| r == g && g == b;
+    plugins: [
+        prae::impl_deref,
+    ];
+}
+
+#[test]
+fn tuple_inner_is_validated() {
+    let r = Range::new((1i64, 5i64)).unwrap();
+    assert_eq!(r.get(), &(1, 5));
+    let (lo, hi) = *r;
+    assert_eq!(hi - lo, 4);
+    assert!(Range::new((5i64, 1i64)).is_err());
+}
+
+#[test]
+fn tuple_inner_error_is_formatted() {
+    let err = Range::new((5i64, 1i64)).unwrap_err();
+    assert_eq!(err.value, (5, 1));
+    assert_eq!(
+        err.to_string(),
+        "failed to construct type Range from value (5, 1): value is invalid"
+    );
+}
+
+#[test]
+fn tuple_inner_is_adjusted() {
+    let s = Span::new((7usize, 3usize)).unwrap();
+    assert_eq!(s.get(), &(3, 7));
+    let err = Span::new((2usize, 2usize)).unwrap_err();
+    assert_eq!(err.original, SpanError::Empty);
+}
+
+#[test]
+fn tuple_inner_is_mutated() {
+    let mut r = Range::new((1i64, 5i64)).unwrap();
+    r.mutate(|(_, hi)| *hi = 10).unwrap();
+    assert_eq!(r.get(), &(1, 10));
+    assert!(r.mutate(|(lo, _)| *lo = 11).is_err());
+    assert_eq!(r.get(), &(1, 10));
+}
+
+#[test]
+fn struct_inner_is_validated() {
+    let g = Gray::new(Rgb {
+        r: 10,
+        g: 10,
+        b: 10,
+    })
+    .unwrap();
+    assert_eq!(g.r, 10);
+    let err = Gray::new(Rgb { r: 1, g: 2, b: 3 }).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to construct type Gray from value Rgb { r: 1, g: 2, b: 3 }: value is invalid"
+    );
+}
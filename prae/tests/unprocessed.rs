@@ -1,3 +1,5 @@
+#![allow(deprecated)]
+
 #[cfg(feature = "unprocessed")]
 mod tests {
     use prae::Wrapper;
@@ -23,4 +25,27 @@ mod tests {
         u.mutate_unprocessed(|u| *u = "".to_owned());
         assert_eq!(u.get(), "");
     }
+
+    #[test]
+    fn as_inner_mut_unchecked_can_be_renormalized() {
+        prae::define! {
+            #[derive(Debug)]
+            pub Trimmed: String;
+            adjust |t| *t = t.trim().to_owned();
+            ensure |t| !t.is_empty();
+        }
+        let mut t = Trimmed::new("value").unwrap();
+        t.as_inner_mut_unchecked().push_str("   ");
+        assert_eq!(t.get(), "value   ");
+        t.renormalize().unwrap();
+        assert_eq!(t.get(), "value");
+    }
+
+    #[test]
+    fn as_inner_mut_unchecked_never_validates() {
+        let mut u = Username::new_unprocessed("lala");
+        u.as_inner_mut_unchecked().clear();
+        assert_eq!(u.get(), "");
+        assert!(u.verify().is_err());
+    }
 }
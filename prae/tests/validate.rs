@@ -87,3 +87,83 @@ fn mutation_succeeds_for_valid_data() {
     un.mutate(|u| *u = " new user ".to_owned()).unwrap();
     assert_eq!(un.get(), " new user ");
 }
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum VersionError {
+    Parse(std::num::ParseIntError),
+    Zero,
+}
+
+impl From<std::num::ParseIntError> for VersionError {
+    fn from(err: std::num::ParseIntError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+impl std::fmt::Display for VersionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse(err) => write!(f, "version is not a number: {}", err),
+            Self::Zero => write!(f, "version is zero"),
+        }
+    }
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Version: String;
+    validate(VersionError) |v| {
+        if v.parse::<u32>()? == 0 {
+            return Err(VersionError::Zero);
+        }
+        Ok(())
+    };
+}
+
+#[test]
+fn question_mark_converts_errors_with_from() {
+    assert!(Version::new("3").is_ok());
+    assert_matches!(
+        Version::new("x").unwrap_err().original,
+        VersionError::Parse(_)
+    );
+    assert_eq!(Version::new("0").unwrap_err().original, VersionError::Zero);
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InvalidSegment {
+    pub range: std::ops::Range<usize>,
+    pub snippet: String,
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub DottedPath: String;
+    validate(InvalidSegment) |p: &String| {
+        let mut start = 0;
+        for segment in p.split('.') {
+            let end = start + segment.len();
+            if segment.is_empty() || !segment.chars().all(char::is_alphanumeric) {
+                return Err(InvalidSegment {
+                    range: start..end,
+                    snippet: segment.to_owned(),
+                });
+            }
+            start = end + 1;
+        }
+        Ok(())
+    };
+}
+
+#[test]
+fn errors_can_describe_the_offending_input() {
+    assert!(DottedPath::new("a.b.c").is_ok());
+
+    let err = DottedPath::new("a.b-c.d").unwrap_err();
+    assert_eq!(err.original.snippet, "b-c");
+    assert_eq!(&err.value[err.original.range.clone()], "b-c");
+
+    let mut path = DottedPath::new("a").unwrap();
+    let err = path.try_set("a..b").unwrap_err();
+    assert_eq!(err.original.range, 2..2);
+}
@@ -0,0 +1,96 @@
+use assert_matches::assert_matches;
+use prae::Wrapper;
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+fn block_on<F: Future>(f: F) -> F::Output {
+    let mut f = pin!(f);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(v) = f.as_mut().poll(&mut cx) {
+            return v;
+        }
+    }
+}
+
+async fn is_taken(username: &str) -> bool {
+    username == "admin"
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum UsernameError {
+    Empty,
+    Taken,
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    adjust |u| *u = u.trim().to_owned();
+    validate(UsernameError) |u| {
+        if u.is_empty() {
+            Err(UsernameError::Empty)
+        } else {
+            Ok(())
+        }
+    };
+    validate_async(UsernameError) async |u: &String| {
+        if is_taken(u).await {
+            Err(UsernameError::Taken)
+        } else {
+            Ok(())
+        }
+    };
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Nickname: String;
+    validate_async(&'static str) |n| async move {
+        let n: &String = n;
+        if is_taken(n).await {
+            Err("nickname is taken")
+        } else {
+            Ok(())
+        }
+    };
+}
+
+#[test]
+fn async_construction_succeeds_for_valid_data() {
+    let un = block_on(Username::new_async(" user ")).unwrap();
+    assert_eq!(un.get(), "user");
+}
+
+#[test]
+fn async_construction_fails_for_sync_invalid_data() {
+    assert_matches!(
+        block_on(Username::new_async("  ")),
+        Err(prae::ConstructionError { original, .. }) if original == UsernameError::Empty
+    );
+}
+
+#[test]
+fn async_construction_fails_for_async_invalid_data() {
+    assert_matches!(
+        block_on(Username::new_async(" admin ")),
+        Err(prae::ConstructionError { original, value, .. }) if original == UsernameError::Taken && value == "admin"
+    );
+}
+
+#[test]
+fn async_closure_alone_defines_error_type() {
+    assert!(block_on(Nickname::new_async("nick")).is_ok());
+    assert_matches!(
+        block_on(Nickname::new_async("admin")),
+        Err(prae::ConstructionError { original, .. }) if original == "nickname is taken"
+    );
+}
+
+#[test]
+fn sync_construction_skips_async_closure() {
+    let un = Username::new(" admin ").unwrap();
+    assert_eq!(un.get(), "admin");
+    assert!(Username::new("  ").is_err());
+}
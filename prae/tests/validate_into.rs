@@ -0,0 +1,48 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    adjust prae::adjust::trim;
+    ensure |u: &String| !u.is_empty();
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Handle: String;
+    adjust |h| h.make_ascii_lowercase();
+    ensure("handle is too long") |h: &String| h.len() <= 5;
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    pub ShortUsername: Username;
+    ensure |u: &String| u.len() <= 5;
+}
+
+#[test]
+fn validate_into_processes_with_the_target() {
+    let handle: Handle = Username::new(" Alice ").unwrap().validate_into().unwrap();
+    assert_eq!(handle.get(), "alice");
+}
+
+#[test]
+fn validate_into_reports_target_errors() {
+    let err = Username::new("Bartholomew")
+        .unwrap()
+        .validate_into::<Handle>()
+        .unwrap_err();
+    assert_eq!(err.original, "handle is too long");
+    assert_eq!(err.value, "bartholomew");
+}
+
+#[test]
+fn validate_into_works_along_extend_chains() {
+    let short: ShortUsername = Username::new("bob").unwrap().validate_into().unwrap();
+    let back: Username = short.validate_into().unwrap();
+    assert_eq!(back.get(), "bob");
+    assert!(Username::new("robert")
+        .unwrap()
+        .validate_into::<ShortUsername>()
+        .is_err());
+}
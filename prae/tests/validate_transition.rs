@@ -0,0 +1,93 @@
+use assert_matches::assert_matches;
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Counter: u64;
+    validate_transition(&'static str) |old, new| {
+        if new < old {
+            Err("counter can't decrease")
+        } else {
+            Ok(())
+        }
+    };
+}
+
+prae::define! {
+    #[derive(Debug)]
+    pub Level: u8;
+    ensure_range(1, 10);
+    validate_transition(&'static str) |old, new| {
+        if new.abs_diff(*old) > 1 {
+            Err("level can change only by one")
+        } else {
+            Ok(())
+        }
+    };
+}
+
+prae::extend! {
+    #[derive(Debug)]
+    pub EvenLevel: Level;
+    ensure |l| l % 2 == 0;
+}
+
+#[test]
+fn construction_ignores_transition() {
+    assert!(Counter::new(0u64).is_ok());
+    assert!(Level::new(10u8).is_ok());
+}
+
+#[test]
+fn set_fails_for_invalid_transition() {
+    let mut c = Counter::new(5u64).unwrap();
+    assert_matches!(
+        c.try_set(4u64),
+        Err(prae::ConstructionError { original, value, .. }) if original == "counter can't decrease" && value == 4
+    );
+    assert_eq!(*c.get(), 5);
+    c.set(6u64);
+    assert_eq!(*c.get(), 6);
+}
+
+#[test]
+fn mutate_fails_for_invalid_transition() {
+    let mut c = Counter::new(5u64).unwrap();
+    assert_matches!(
+        c.mutate(|c| *c -= 1),
+        Err(prae::MutationError { original, old_value: Some(5), new_value: 4 }) if original == "counter can't decrease"
+    );
+    c.mutate(|c| *c += 1).unwrap();
+    assert_eq!(*c.get(), 6);
+}
+
+#[test]
+fn commit_fails_for_invalid_transition() {
+    let mut c = Counter::new(5u64).unwrap();
+    let mut checkout = c.checkout();
+    *checkout = 0;
+    assert_matches!(checkout.commit(), Err(prae::MutationError { .. }));
+    assert_eq!(*c.get(), 5);
+}
+
+#[test]
+fn transition_is_validated_after_process() {
+    let mut l = Level::new(1u8).unwrap();
+    assert_matches!(
+        l.try_set(0u8),
+        Err(prae::ConstructionError { original, .. }) if original == "value is out of range"
+    );
+    assert_matches!(
+        l.try_set(3u8),
+        Err(prae::ConstructionError { original, .. }) if original == "level can change only by one"
+    );
+}
+
+#[test]
+fn extended_inherits_transition() {
+    let mut l = EvenLevel::new(2u8).unwrap();
+    assert_matches!(
+        l.try_set(6u8),
+        Err(prae::ConstructionError { original, .. }) if original == "level can change only by one"
+    );
+}
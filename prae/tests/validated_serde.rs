@@ -0,0 +1,61 @@
+#[cfg(feature = "serde")]
+mod tests {
+    use prae::Wrapper;
+
+    prae::define! {
+        #[derive(Debug)]
+        pub Username: String;
+        adjust |u| *u = u.trim().to_owned();
+        ensure |u| !u.is_empty();
+        validated_serde;
+        plugins: [
+            prae::impl_display,
+        ];
+    }
+
+    prae::define! {
+        #[derive(Debug)]
+        pub NonEmpty<T>: Vec<T> where T: Clone;
+        ensure |v| !v.is_empty();
+        validated_serde;
+    }
+
+    #[test]
+    fn deserialization_runs_validation() {
+        let u: Username = serde_json::from_str(r#"" user ""#).unwrap();
+        assert_eq!(u.get(), "user");
+        assert!(serde_json::from_str::<Username>(r#""   ""#).is_err());
+    }
+
+    #[test]
+    fn serialization_succeeds() {
+        let u = Username::new("user").unwrap();
+        assert_eq!(serde_json::to_string(&u).unwrap(), r#""user""#);
+        assert_eq!(u.to_string(), "user");
+    }
+
+    #[test]
+    fn generic_deserialization_runs_validation() {
+        let v: NonEmpty<u8> = serde_json::from_str("[1, 2]").unwrap();
+        assert_eq!(v.get(), &vec![1, 2]);
+        assert_eq!(serde_json::to_string(&v).unwrap(), "[1,2]");
+        assert!(serde_json::from_str::<NonEmpty<u8>>("[]").is_err());
+    }
+
+    #[allow(deprecated)]
+    mod derived {
+        use prae::Wrapper;
+
+        prae::define! {
+            #[derive(Debug, serde::Deserialize)]
+            pub Unchecked: String;
+            ensure |u| !u.is_empty();
+        }
+
+        #[test]
+        fn derived_deserialization_skips_validation() {
+            let u: Unchecked = serde_json::from_str(r#""""#).unwrap();
+            assert_eq!(u.get(), "");
+        }
+    }
+}
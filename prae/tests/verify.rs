@@ -0,0 +1,39 @@
+use assert_matches::assert_matches;
+use prae::{MapOriginalError, Wrapper};
+
+prae::define! {
+    #[derive(Debug)]
+    pub Username: String;
+    adjust |u| *u = u.trim().to_owned();
+    ensure |u| !u.is_empty();
+}
+
+#[test]
+fn verification_succeeds_for_valid_value() {
+    let u = Username::new("user").unwrap().verify().unwrap();
+    assert_eq!(u.get(), "user");
+}
+
+#[test]
+fn verification_adjusts_value() {
+    let u = Username::new_unprocessed(" user ").verify().unwrap();
+    assert_eq!(u.get(), "user");
+}
+
+#[test]
+fn verification_fails_for_invalid_value() {
+    assert_matches!(
+        Username::new_unprocessed("   ").verify(),
+        Err(prae::VerificationError { value, original: "value is invalid" }) if value.is_empty()
+    );
+}
+
+#[test]
+fn verification_error_maps_to_original() {
+    fn load(raw: &str) -> Result<Username, &'static str> {
+        let u = Username::new_unprocessed(raw).verify().map_original()?;
+        Ok(u)
+    }
+    assert_eq!(load(" user ").unwrap().get(), "user");
+    assert_eq!(load("   ").unwrap_err(), "value is invalid");
+}
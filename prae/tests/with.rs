@@ -0,0 +1,31 @@
+use prae::Wrapper;
+
+prae::define! {
+    #[derive(Debug)]
+    pub Email: String;
+    ensure |e: &String| e.contains('@');
+}
+
+fn domain(email: &str) -> &str {
+    email.split_once('@').map_or("", |(_, domain)| domain)
+}
+
+#[test]
+fn computes_value_from_inner() {
+    let e = Email::new("user@example.com").unwrap();
+    assert_eq!(e.with(String::len), 16);
+    assert!(e.with(|e| e.starts_with("user")));
+}
+
+#[test]
+fn result_can_borrow_from_inner() {
+    let e = Email::new("user@example.com").unwrap();
+    assert_eq!(e.with(|e| domain(e)), "example.com");
+}
+
+#[test]
+fn works_in_iterator_adapters() {
+    let emails = ["a@x.com", "b@y.org"].map(|e| Email::new(e).unwrap());
+    let domains: Vec<_> = emails.iter().map(|e| e.with(|e| domain(e))).collect();
+    assert_eq!(domains, ["x.com", "y.org"]);
+}
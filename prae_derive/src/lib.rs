@@ -0,0 +1,188 @@
+//! Derive macro for the [`prae`](https://docs.rs/prae) crate.
+//!
+//! Don't depend on this crate directly. Enable the `derive` feature of `prae`
+//! and use [`prae::Validate`](https://docs.rs/prae/latest/prae/derive.Validate.html)
+//! instead.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Expr, Fields, LitStr, Type};
+
+/// Implements `prae::Wrapper` for a single-field tuple struct.
+///
+/// This is an attribute based alternative to `prae::define!` for those who
+/// prefer to keep the struct definition as a plain Rust item. The closures are
+/// configured with the `#[prae(...)]` attribute:
+///
+/// Argument | Description
+/// ---|---
+/// `adjust = <expr>` | Same as the `adjust` closure of `define!`. May be repeated.
+/// `ensure = <expr>` | Same as the `ensure` closure of `define!`.
+/// `message = "<msg>"` | Error message of the `ensure` closure.
+/// `validate = <expr>` | Same as the `validate` closure of `define!`. Requires `error`.
+/// `error = <type>` | Error type of the `validate` closure.
+///
+/// Every expression may be a path to a function or a closure. As with
+/// `define!`, closures can't capture their environment.
+///
+/// ```
+/// use prae::Wrapper;
+///
+/// fn trim(value: &mut String) {
+///     *value = value.trim().to_owned();
+/// }
+///
+/// #[derive(Debug, prae::Validate)]
+/// #[prae(adjust = trim, ensure = |v: &String| !v.is_empty())]
+/// #[prae(message = "username is empty")]
+/// pub struct Username(String);
+///
+/// let username = Username::new(" user ").unwrap();
+/// assert_eq!(username.get(), "user");
+///
+/// let err = Username::new("  ").unwrap_err();
+/// assert_eq!(err.original, "username is empty");
+/// ```
+///
+/// The derive supports only the core closures listed above and doesn't accept
+/// generic structs. Use `define!` for everything else.
+/// ```compile_fail
+/// #[derive(prae::Validate)]
+/// struct Pair(u8, u8);
+/// ```
+#[proc_macro_derive(Validate, attributes(prae))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[derive(Default)]
+struct Config {
+    adjust: Vec<Expr>,
+    ensure: Option<Expr>,
+    message: Option<LitStr>,
+    validate: Option<Expr>,
+    error: Option<Type>,
+}
+
+fn parse_config(input: &DeriveInput) -> syn::Result<Config> {
+    let mut config = Config::default();
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("prae"))
+    {
+        attr.parse_nested_meta(|meta| {
+            let duplicate = || meta.error("duplicate argument");
+            if meta.path.is_ident("adjust") {
+                config.adjust.push(meta.value()?.parse()?);
+            } else if meta.path.is_ident("ensure") {
+                if config.ensure.is_some() {
+                    return Err(duplicate());
+                }
+                config.ensure = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("message") {
+                if config.message.is_some() {
+                    return Err(duplicate());
+                }
+                config.message = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("validate") {
+                if config.validate.is_some() {
+                    return Err(duplicate());
+                }
+                config.validate = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("error") {
+                if config.error.is_some() {
+                    return Err(duplicate());
+                }
+                config.error = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error(
+                    "unknown argument, expected `adjust`, `ensure`, `message`, `validate` or `error`",
+                ));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(config)
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    if !input.generics.params.is_empty() || input.generics.where_clause.is_some() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "`prae::Validate` doesn't support generic structs, use `prae::define!` instead",
+        ));
+    }
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            ident.span(),
+            "`prae::Validate` can only be derived for structs",
+        ));
+    };
+    let inner = match &data.fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+        fields => {
+            return Err(syn::Error::new(
+                fields.span(),
+                "`prae::Validate` requires a tuple struct with a single field",
+            ))
+        }
+    };
+
+    let config = parse_config(&input)?;
+    let closures = match (&config.ensure, &config.validate, &config.error) {
+        (Some(_), Some(validate), _) => {
+            return Err(syn::Error::new(
+                validate.span(),
+                "`ensure` and `validate` can't be used together",
+            ))
+        }
+        (_, Some(validate), None) => {
+            return Err(syn::Error::new(
+                validate.span(),
+                "`validate` requires the `error` argument",
+            ))
+        }
+        (_, None, Some(error)) => {
+            return Err(syn::Error::new(
+                error.span(),
+                "`error` can only be used with `validate`",
+            ))
+        }
+        (Some(ensure), None, None) => match &config.message {
+            Some(message) => quote!(ensure @message(#message) #ensure;),
+            None => quote!(ensure #ensure;),
+        },
+        (None, Some(validate), Some(error)) => quote!(validate(#error) #validate;),
+        (None, None, None) => quote!(),
+    };
+    if let (Some(message), None) = (&config.message, &config.ensure) {
+        return Err(syn::Error::new(
+            message.span(),
+            "`message` can only be used with `ensure`",
+        ));
+    }
+    let adjust = &config.adjust;
+
+    Ok(quote! {
+        impl ::prae::Wrapper for #ident {
+            const NAME: &'static str = stringify!(#ident);
+            type Inner = #inner;
+            const ADJUST: fn(&mut Self::Inner) = |_v| {
+                #({
+                    let adjust: fn(&mut Self::Inner) = #adjust;
+                    adjust(_v);
+                })*
+            };
+            ::prae::define!(#closures);
+            ::prae::__impl_wrapper_methods!();
+        }
+        ::prae::__impl_external_traits!(#ident, #inner);
+    })
+}